@@ -1,25 +1,172 @@
 #![allow(deprecated)]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, String, Symbol,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, IntoVal, Map,
+    String, Symbol, TryFromVal, Val, Vec,
 };
 
 const ADMIN_KEY: &Symbol = &symbol_short!("ADMIN");
-const PAUSED_KEY: &Symbol = &symbol_short!("PAUSED");
+const PAUSE_FLAGS_KEY: &Symbol = &symbol_short!("PAUSE_FLG");
 const NEXT_TOKEN_ID_KEY: &Symbol = &symbol_short!("NEXT_ID");
 const BASE_URI_KEY: &Symbol = &symbol_short!("BASE_URI");
 const LOCKED_KEY: &Symbol = &symbol_short!("LOCKED");
 const EVENT_CONTRACT_KEY: &Symbol = &symbol_short!("EVENT_CTR");
+const CLAIM_TTL_KEY: &Symbol = &symbol_short!("CLAIM_TTL");
+const ISSUE_QUOTA_KEY: &Symbol = &symbol_short!("ISS_QUOTA");
+const GUARDIANS_KEY: &Symbol = &symbol_short!("GUARDIANS");
+const GUARD_THRESHOLD_KEY: &Symbol = &symbol_short!("GUARD_THR");
+const RECOVERY_KEY: &Symbol = &symbol_short!("RECOVERY");
+const RECOVERY_WINDOW_KEY: &Symbol = &symbol_short!("REC_WIN");
+const RECOVERY_DELAY_KEY: &Symbol = &symbol_short!("REC_DELAY");
+const PENDING_ADMIN_KEY: &Symbol = &symbol_short!("PEND_ADM");
+const COUNCIL_KEY: &Symbol = &symbol_short!("COUNCIL");
+const COUNCIL_THRESHOLD_KEY: &Symbol = &symbol_short!("CNCL_THR");
+const NEXT_PROPOSAL_ID_KEY: &Symbol = &symbol_short!("NEXT_PROP");
+
+const DEFAULT_CLAIM_TTL_SECS: u64 = 7 * 86400;
+const DEFAULT_ISSUANCE_QUOTA: u32 = 100;
+const DEFAULT_RECOVERY_WINDOW_SECS: u64 = 3 * 86400;
+const DEFAULT_RECOVERY_DELAY_SECS: u64 = 2 * 86400;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 7 * DAY_IN_LEDGERS;
+
+/// Schema version carried as the first data field of the events whose
+/// topics presence-events publishes too, so indexers see one schema.
+pub(crate) const EVENT_VERSION: u32 = 1;
 
 const ROLE_ADMIN: u32 = 1;
 const ROLE_SUPERVISOR: u32 = 2;
 const ROLE_ASSOCIATE: u32 = 3;
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    AdminNotSet = 2,
+    Paused = 3,
+    Reentrancy = 4,
+    InvalidClaimToken = 5,
+    TokenAlreadyUsed = 6,
+    RecipientMismatch = 7,
+    AlreadyHasRole = 8,
+    CredentialNotFound = 9,
+    /// Deprecated: no entrypoint returns this code any more. The slot stays
+    /// reserved so clients decoding older transactions keep its meaning.
+    NotAuthorized = 10,
+    NotAllowed = 11,
+    ClaimRevoked = 12,
+    ClaimExpired = 13,
+    InvalidExpiry = 14,
+    SecretRequired = 15,
+    InvalidMaxUses = 16,
+    DuplicateClaim = 17,
+    QuotaExceeded = 18,
+    NotGuardian = 19,
+    InvalidThreshold = 20,
+    InvalidGuardians = 21,
+    RecoveryPending = 22,
+    NoRecoveryPending = 23,
+    AlreadyApproved = 24,
+    ApprovalWindowClosed = 25,
+    TimelockActive = 26,
+    NoPendingAdmin = 27,
+    AdminProposalExpired = 28,
+    CouncilRequired = 29,
+    NotCouncilMember = 30,
+    InvalidCouncil = 31,
+    ProposalNotFound = 32,
+    ProposalExpired = 33,
+    ProposalExecuted = 34,
+    ThresholdNotMet = 35,
+}
+
 #[contracttype]
 pub enum DataKey {
     RoleByWallet(Address),
     TokenById(u64),
     ClaimToken(BytesN<32>),
-    Authorized(Address),
+    IssuanceQuota(Address),
+    IssuedCount(Address),
+    CouncilProposal(u64),
+}
+
+/// Emergency-stop flags. presence-events has its own flags and storage;
+/// each contract is paused separately. Each flag halts a group of
+/// entrypoints; see `set_pause`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseStatus {
+    pub claims: bool,
+    pub issuance: bool,
+    pub admin: bool,
+}
+
+impl PauseStatus {
+    fn all(paused: bool) -> Self {
+        PauseStatus { claims: paused, issuance: paused, admin: paused }
+    }
+
+    fn any(&self) -> bool {
+        self.claims || self.issuance || self.admin
+    }
+
+    /// Flags set in `self` but not in `other`.
+    fn without(&self, other: &PauseStatus) -> Self {
+        PauseStatus {
+            claims: self.claims && !other.claims,
+            issuance: self.issuance && !other.issuance,
+            admin: self.admin && !other.admin,
+        }
+    }
+}
+
+/// Privileged operations the admin council decides on.
+#[contracttype]
+#[derive(Clone)]
+pub enum AdminAction {
+    Pause,
+    Unpause,
+    SetPause(PauseStatus),
+    RevokeCredential(Address, u32),
+    SetEventContract(String),
+    SetCouncil(Vec<Address>, u32),
+    DissolveCouncil,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CouncilProposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+/// Admin handover awaiting acceptance by `new_admin`.
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    pub expires_at: u64,
+}
+
+/// A guardian-initiated admin recovery. Becomes executable once
+/// `approvals` reaches the threshold, after the recovery timelock.
+#[contracttype]
+#[derive(Clone)]
+pub struct RecoveryProposal {
+    pub new_admin: Address,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub approve_by: u64,
+    pub executable_at: Option<u64>,
 }
 
 #[contracttype]
@@ -29,12 +176,27 @@ pub struct TokenData {
     pub owner: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ClaimStatus {
+    Valid = 0,
+    Used = 1,
+    Revoked = 2,
+    Expired = 3,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct ClaimData {
     pub role_id: u32,
-    pub recipient: Address,
-    pub valid: bool,
+    /// `None` for open links, redeemable by whoever presents the secret.
+    pub recipient: Option<Address>,
+    pub issuer: Address,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub expires_at: u64,
+    pub status: ClaimStatus,
 }
 
 #[contract]
@@ -42,152 +204,453 @@ pub struct OwnerRules;
 
 #[contractimpl]
 impl OwnerRules {
-    pub fn initialize(env: &Env, admin: Address) {
-        if env.storage().instance().has(ADMIN_KEY) { panic!("already initialized"); }
+    pub fn initialize(env: &Env, admin: Address) -> Result<(), Error> {
+        if env.storage().instance().has(ADMIN_KEY) { return Err(Error::AlreadyInitialized); }
         admin.require_auth();
         env.storage().instance().set(ADMIN_KEY, &admin);
-        env.storage().instance().set(PAUSED_KEY, &false);
+        env.storage().instance().set(PAUSE_FLAGS_KEY, &PauseStatus::all(false));
         env.storage().instance().set(NEXT_TOKEN_ID_KEY, &1u64);
         env.storage().instance().set(BASE_URI_KEY, &String::from_str(env, ""));
         env.storage().instance().set(LOCKED_KEY, &false);
         let token_id = Self::next_id_and_increment(env);
         let data = TokenData { role_id: ROLE_ADMIN, owner: admin.clone() };
-        env.storage().instance().set(&DataKey::TokenById(token_id), &data);
+        Self::write_persistent(env, &DataKey::TokenById(token_id), &data);
         let mut roles: Map<u32, u64> = Self::roles_for(env, &admin);
         roles.set(ROLE_ADMIN, token_id);
-        env.storage().instance().set(&DataKey::RoleByWallet(admin.clone()), &roles);
+        Self::write_persistent(env, &DataKey::RoleByWallet(admin.clone()), &roles);
         Self::emit_credential_minted(env, ROLE_ADMIN, admin, token_id);
+        Ok(())
     }
     pub fn __constructor(env: &Env, admin: Address) {
         env.storage().instance().set(ADMIN_KEY, &admin);
-        env.storage().instance().set(PAUSED_KEY, &false);
+        env.storage().instance().set(PAUSE_FLAGS_KEY, &PauseStatus::all(false));
         env.storage().instance().set(NEXT_TOKEN_ID_KEY, &1u64);
         env.storage().instance().set(BASE_URI_KEY, &String::from_str(env, ""));
         env.storage().instance().set(LOCKED_KEY, &false);
         let token_id = Self::next_id_and_increment(env);
         let data = TokenData { role_id: ROLE_ADMIN, owner: admin.clone() };
-        env.storage().instance().set(&DataKey::TokenById(token_id), &data);
+        Self::write_persistent(env, &DataKey::TokenById(token_id), &data);
         let mut roles: Map<u32, u64> = Self::roles_for(env, &admin);
         roles.set(ROLE_ADMIN, token_id);
-        env.storage().instance().set(&DataKey::RoleByWallet(admin.clone()), &roles);
+        Self::write_persistent(env, &DataKey::RoleByWallet(admin.clone()), &roles);
         Self::emit_credential_minted(env, ROLE_ADMIN, admin, token_id);
     }
 
-    pub fn transfer_admin(env: &Env, new_admin: Address) {
-        Self::require_admin(env);
-        let old_admin = Self::admin(env).expect("admin not set");
-        if let Some(old_token) = Self::role_token_for(env, &old_admin, ROLE_ADMIN) {
-            env.storage().instance().remove(&DataKey::TokenById(old_token));
-            let mut roles_old: Map<u32, u64> = Self::roles_for(env, &old_admin);
-            roles_old.remove(ROLE_ADMIN);
-            env.storage().instance().set(&DataKey::RoleByWallet(old_admin.clone()), &roles_old);
-            Self::emit_credential_revoked(env, ROLE_ADMIN, old_admin.clone(), old_token);
-        }
-        env.storage().instance().set(ADMIN_KEY, &new_admin);
-        let token_id = Self::next_id_and_increment(env);
-        let data = TokenData { role_id: ROLE_ADMIN, owner: new_admin.clone() };
-        env.storage().instance().set(&DataKey::TokenById(token_id), &data);
-        let mut roles_new: Map<u32, u64> = Self::roles_for(env, &new_admin);
-        roles_new.set(ROLE_ADMIN, token_id);
-        env.storage().instance().set(&DataKey::RoleByWallet(new_admin.clone()), &roles_new);
-        Self::emit_admin_transferred(env, old_admin, new_admin.clone());
-        Self::emit_credential_minted(env, ROLE_ADMIN, new_admin, token_id);
+    /// First step of an admin handover. Nothing changes until `new_admin`
+    /// calls `accept_admin` before `expires_at`. Blocked by the `admin`
+    /// pause flag.
+    pub fn propose_admin(env: &Env, new_admin: Address, expires_at: u64) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.admin)?;
+        Self::require_admin(env)?;
+        if expires_at <= env.ledger().timestamp() { return Err(Error::InvalidExpiry); }
+        let admin = Self::admin(env).ok_or(Error::AdminNotSet)?;
+        env.storage().instance().set(PENDING_ADMIN_KEY, &PendingAdmin { new_admin: new_admin.clone(), expires_at });
+        Self::emit_admin_proposed(env, admin, new_admin, expires_at);
+        Ok(())
+    }
+
+    /// Completes the handover, moving the `ROLE_ADMIN` credential as well.
+    pub fn accept_admin(env: &Env) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.admin)?;
+        let pending: PendingAdmin = env.storage().instance().get(PENDING_ADMIN_KEY).ok_or(Error::NoPendingAdmin)?;
+        if env.ledger().timestamp() > pending.expires_at { return Err(Error::AdminProposalExpired); }
+        pending.new_admin.require_auth();
+        Self::set_admin(env, pending.new_admin);
+        Ok(())
+    }
+
+    pub fn cancel_admin_proposal(env: &Env) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        let pending: PendingAdmin = env.storage().instance().get(PENDING_ADMIN_KEY).ok_or(Error::NoPendingAdmin)?;
+        env.storage().instance().remove(PENDING_ADMIN_KEY);
+        Self::emit_admin_proposal_cancelled(env, pending.new_admin);
+        Ok(())
     }
 
-    pub fn pause(env: &Env) {
-        Self::require_admin(env);
-        env.storage().instance().set(PAUSED_KEY, &true);
+    pub fn get_admin(env: &Env) -> Result<Address, Error> {
+        Self::admin(env).ok_or(Error::AdminNotSet)
     }
 
-    pub fn unpause(env: &Env) {
-        Self::require_admin(env);
-        env.storage().instance().set(PAUSED_KEY, &false);
+    /// Returns the outstanding proposal, if any. An expired proposal stays
+    /// visible until it is cancelled or replaced.
+    pub fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+        env.storage().instance().get(PENDING_ADMIN_KEY)
     }
 
-    pub fn set_base_uri(env: &Env, new_uri: String) {
-        Self::require_admin(env);
+    /// Raises every pause flag.
+    pub fn pause(env: &Env) -> Result<(), Error> {
+        Self::require_sole_admin(env)?;
+        Self::apply_pause(env, PauseStatus::all(true));
+        Ok(())
+    }
+
+    pub fn unpause(env: &Env) -> Result<(), Error> {
+        Self::require_sole_admin(env)?;
+        Self::apply_pause(env, PauseStatus::all(false));
+        Ok(())
+    }
+
+    /// Sets each pause flag individually:
+    /// - `claims`: `claim_nft` and `claim_nft_with_secret`.
+    /// - `issuance`: claim link generation and credential revocation,
+    ///   including revocations run by the council.
+    /// - `admin`: `propose_admin` and `accept_admin`.
+    ///
+    /// Cancelling an admin proposal, guardian recovery and the other council
+    /// actions stay available so a paused contract can still be recovered.
+    pub fn set_pause(env: &Env, status: PauseStatus) -> Result<(), Error> {
+        Self::require_sole_admin(env)?;
+        Self::apply_pause(env, status);
+        Ok(())
+    }
+
+    pub fn pause_status(env: &Env) -> PauseStatus {
+        env.storage().instance().get(PAUSE_FLAGS_KEY).unwrap_or(PauseStatus::all(false))
+    }
+
+    pub fn set_base_uri(env: &Env, new_uri: String) -> Result<(), Error> {
+        Self::require_admin(env)?;
         env.storage().instance().set(BASE_URI_KEY, &new_uri);
         Self::emit_base_uri_updated(env, new_uri);
+        Ok(())
     }
 
-    pub fn set_event_contract(env: &Env, contract_id: String) {
-        Self::require_admin(env);
-        env.storage().instance().set(EVENT_CONTRACT_KEY, &contract_id);
-        Self::emit_event_contract_updated(env, contract_id);
+    pub fn set_event_contract(env: &Env, contract_id: String) -> Result<(), Error> {
+        Self::require_sole_admin(env)?;
+        Self::apply_event_contract(env, contract_id);
+        Ok(())
     }
 
     pub fn get_event_contract(env: &Env) -> Option<String> {
         env.storage().instance().get(EVENT_CONTRACT_KEY)
     }
 
-    pub fn authorize_contract(env: &Env, contract: Address, status: bool) {
-        Self::require_admin(env);
-        env.storage().instance().set(&DataKey::Authorized(contract), &status);
+    /// Replaces the guardian set. Any pending recovery is discarded since
+    /// its approvals came from the previous set. An empty set with a zero
+    /// threshold disables recovery.
+    pub fn set_guardians(env: &Env, guardians: Vec<Address>, threshold: u32) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        if guardians.is_empty() {
+            if threshold != 0 { return Err(Error::InvalidThreshold); }
+        } else if threshold == 0 || threshold > guardians.len() {
+            return Err(Error::InvalidThreshold);
+        }
+        for (i, g) in guardians.iter().enumerate() {
+            if guardians.iter().skip(i + 1).any(|other| other == g) { return Err(Error::InvalidGuardians); }
+        }
+        if guardians.is_empty() {
+            env.storage().instance().remove(GUARDIANS_KEY);
+            env.storage().instance().remove(GUARD_THRESHOLD_KEY);
+        } else {
+            env.storage().instance().set(GUARDIANS_KEY, &guardians);
+            env.storage().instance().set(GUARD_THRESHOLD_KEY, &threshold);
+        }
+        env.storage().instance().remove(RECOVERY_KEY);
+        Self::emit_guardians_set(env, guardians, threshold);
+        Ok(())
     }
 
-    pub fn recover_admin(env: &Env, new_admin: Address, operator: Address) {
-        operator.require_auth();
-        let authorized: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::Authorized(operator.clone()))
-            .unwrap_or(false);
-        if !authorized { panic!("not authorized"); }
-        env.storage().instance().set(ADMIN_KEY, &new_admin);
+    /// Returns the guardian set and the number of approvals a recovery needs.
+    pub fn get_guardians(env: &Env) -> (Vec<Address>, u32) {
+        let guardians = env.storage().instance().get(GUARDIANS_KEY).unwrap_or(Vec::new(env));
+        let threshold = env.storage().instance().get(GUARD_THRESHOLD_KEY).unwrap_or(0);
+        (guardians, threshold)
     }
 
-    pub fn generate_supervisor_claim_link(env: &Env, recipient: Address) -> BytesN<32> {
-        Self::require_admin(env);
-        Self::new_claim(env, recipient, ROLE_SUPERVISOR)
+    /// Sets how long guardians have to reach the threshold and how long the
+    /// admin then has to veto before the recovery can be executed.
+    pub fn set_recovery_timing(env: &Env, approval_window_secs: u64, timelock_secs: u64) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        if approval_window_secs == 0 { return Err(Error::InvalidExpiry); }
+        env.storage().instance().set(RECOVERY_WINDOW_KEY, &approval_window_secs);
+        env.storage().instance().set(RECOVERY_DELAY_KEY, &timelock_secs);
+        Self::emit_recovery_timing(env, approval_window_secs, timelock_secs);
+        Ok(())
     }
 
-    pub fn generate_associate_claim_link(env: &Env, recipient: Address, operator: Address) -> BytesN<32> {
-        let is_admin = Self::has_role(env, Self::admin(env).unwrap_or(recipient.clone()), ROLE_ADMIN);
-        let is_supervisor = Self::has_role(env, operator.clone(), ROLE_SUPERVISOR);
-        if !is_admin && !is_supervisor { panic!("not allowed"); }
-        Self::new_claim(env, recipient, ROLE_ASSOCIATE)
+    /// Returns `(approval_window_secs, timelock_secs)`.
+    pub fn get_recovery_timing(env: &Env) -> (u64, u64) {
+        let window = env.storage().instance().get(RECOVERY_WINDOW_KEY).unwrap_or(DEFAULT_RECOVERY_WINDOW_SECS);
+        let delay = env.storage().instance().get(RECOVERY_DELAY_KEY).unwrap_or(DEFAULT_RECOVERY_DELAY_SECS);
+        (window, delay)
     }
 
-    pub fn claim_nft(env: &Env, token_hash: BytesN<32>, wallet: Address) -> u64 {
-        Self::require_not_paused(env);
-        Self::enter(env);
-        let claim: ClaimData = env
-            .storage()
-            .instance()
-            .get(&DataKey::ClaimToken(token_hash.clone()))
-            .expect("invalid claim token");
-        if !claim.valid { Self::exit(env); panic!("token already used"); }
-        if claim.recipient != wallet { Self::exit(env); panic!("recipient mismatch"); }
-        if Self::role_token_for(env, &wallet, claim.role_id).is_some() {
-            Self::exit(env);
-            panic!("already has role");
+    /// Opens a recovery proposal naming `new_admin`. The proposer's approval
+    /// is counted. Replaces a previous proposal only if it lapsed unapproved.
+    pub fn propose_recovery(env: &Env, guardian: Address, new_admin: Address) -> Result<(), Error> {
+        guardian.require_auth();
+        Self::require_guardian(env, &guardian)?;
+        let now = env.ledger().timestamp();
+        if let Some(pending) = Self::recovery(env)
+            && (pending.executable_at.is_some() || now <= pending.approve_by)
+        {
+            return Err(Error::RecoveryPending);
         }
-        let token_id = Self::next_id_and_increment(env);
-        let data = TokenData { role_id: claim.role_id, owner: wallet.clone() };
-        env.storage().instance().set(&DataKey::TokenById(token_id), &data);
-        let mut roles: Map<u32, u64> = Self::roles_for(env, &wallet);
-        roles.set(claim.role_id, token_id);
-        env.storage().instance().set(&DataKey::RoleByWallet(wallet.clone()), &roles);
-        env.storage().instance().set(&DataKey::ClaimToken(token_hash.clone()), &ClaimData { role_id: claim.role_id, recipient: claim.recipient, valid: false });
-        Self::emit_credential_minted(env, claim.role_id, wallet, token_id);
-        Self::exit(env);
-        token_id
+        let (window, _) = Self::get_recovery_timing(env);
+        let mut proposal = RecoveryProposal {
+            new_admin: new_admin.clone(),
+            proposer: guardian.clone(),
+            approvals: Vec::new(env),
+            created_at: now,
+            approve_by: now.saturating_add(window),
+            executable_at: None,
+        };
+        Self::emit_recovery_proposed(env, guardian.clone(), new_admin, proposal.approve_by);
+        Self::add_recovery_approval(env, &mut proposal, guardian);
+        env.storage().instance().set(RECOVERY_KEY, &proposal);
+        Ok(())
     }
 
-    pub fn revoke_credential(env: &Env, wallet: Address, role_id: u32) {
-        Self::require_admin(env);
-        Self::enter(env);
-        if let Some(token_id) = Self::role_token_for(env, &wallet, role_id) {
-            env.storage().instance().remove(&DataKey::TokenById(token_id));
-            let mut roles: Map<u32, u64> = Self::roles_for(env, &wallet);
-            roles.remove(role_id);
-            env.storage().instance().set(&DataKey::RoleByWallet(wallet.clone()), &roles);
-            Self::emit_credential_revoked(env, role_id, wallet, token_id);
-        } else {
-            Self::exit(env);
-            panic!("credential not found");
+    pub fn approve_recovery(env: &Env, guardian: Address) -> Result<(), Error> {
+        guardian.require_auth();
+        Self::require_guardian(env, &guardian)?;
+        let mut proposal = Self::recovery(env).ok_or(Error::NoRecoveryPending)?;
+        if proposal.executable_at.is_some() || proposal.approvals.contains(&guardian) { return Err(Error::AlreadyApproved); }
+        if env.ledger().timestamp() > proposal.approve_by { return Err(Error::ApprovalWindowClosed); }
+        Self::add_recovery_approval(env, &mut proposal, guardian);
+        env.storage().instance().set(RECOVERY_KEY, &proposal);
+        Ok(())
+    }
+
+    /// Lets the current admin discard a pending recovery, at any point
+    /// before it is executed.
+    pub fn veto_recovery(env: &Env) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        let proposal = Self::recovery(env).ok_or(Error::NoRecoveryPending)?;
+        env.storage().instance().remove(RECOVERY_KEY);
+        Self::emit_recovery_vetoed(env, proposal.proposer, proposal.new_admin);
+        Ok(())
+    }
+
+    /// Hands the admin role to the proposed address once the proposal is
+    /// approved and its timelock has passed. Callable by anyone.
+    pub fn execute_recovery(env: &Env) -> Result<(), Error> {
+        let proposal = Self::recovery(env).ok_or(Error::NoRecoveryPending)?;
+        let Some(executable_at) = proposal.executable_at else {
+            return Err(if env.ledger().timestamp() > proposal.approve_by { Error::ApprovalWindowClosed } else { Error::NoRecoveryPending });
+        };
+        if env.ledger().timestamp() < executable_at { return Err(Error::TimelockActive); }
+        env.storage().instance().remove(RECOVERY_KEY);
+        Self::emit_recovery_executed(env, proposal.new_admin.clone(), proposal.approvals);
+        Self::set_admin(env, proposal.new_admin);
+        Ok(())
+    }
+
+    pub fn get_recovery_proposal(env: &Env) -> Option<RecoveryProposal> {
+        Self::recovery(env)
+    }
+
+    /// Lifetime of claim links generated without an explicit `expires_at`.
+    pub fn set_default_claim_ttl(env: &Env, ttl_secs: u64) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        if ttl_secs == 0 { return Err(Error::InvalidExpiry); }
+        env.storage().instance().set(CLAIM_TTL_KEY, &ttl_secs);
+        Self::emit_default_claim_ttl_set(env, ttl_secs);
+        Ok(())
+    }
+
+    pub fn get_default_claim_ttl(env: &Env) -> u64 {
+        env.storage().instance().get(CLAIM_TTL_KEY).unwrap_or(DEFAULT_CLAIM_TTL_SECS)
+    }
+
+    /// Sets how many associate claims supervisors may issue in total.
+    /// Applies to every supervisor without an individual quota.
+    pub fn set_default_issuance_quota(env: &Env, quota: u32) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        env.storage().instance().set(ISSUE_QUOTA_KEY, &quota);
+        Self::emit_issuance_quota_set(env, None, quota);
+        Ok(())
+    }
+
+    pub fn get_default_issuance_quota(env: &Env) -> u32 {
+        env.storage().instance().get(ISSUE_QUOTA_KEY).unwrap_or(DEFAULT_ISSUANCE_QUOTA)
+    }
+
+    pub fn set_issuance_quota(env: &Env, supervisor: Address, quota: u32) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        Self::write_persistent(env, &DataKey::IssuanceQuota(supervisor.clone()), &quota);
+        Self::emit_issuance_quota_set(env, Some(supervisor), quota);
+        Ok(())
+    }
+
+    /// Returns `(quota, issued)` for `supervisor`.
+    pub fn get_issuance_quota(env: &Env, supervisor: Address) -> (u32, u32) {
+        (Self::issuance_quota_for(env, &supervisor), Self::read_persistent(env, &DataKey::IssuedCount(supervisor)).unwrap_or(0))
+    }
+
+    pub fn generate_supervisor_claim_link(env: &Env, recipient: Address, expires_at: Option<u64>) -> Result<BytesN<32>, Error> {
+        Self::require_admin(env)?;
+        let admin = Self::admin(env).ok_or(Error::AdminNotSet)?;
+        Self::new_claim(env, recipient, ROLE_SUPERVISOR, admin, expires_at)
+    }
+
+    /// Issues an associate invitation. `operator` must be the admin or a
+    /// supervisor; supervisors draw from their issuance quota.
+    pub fn generate_associate_claim_link(env: &Env, recipient: Address, operator: Address, expires_at: Option<u64>) -> Result<BytesN<32>, Error> {
+        Self::authorize_issuer(env, &operator, 1)?;
+        Self::new_claim(env, recipient, ROLE_ASSOCIATE, operator, expires_at)
+    }
+
+    /// Registers an open associate invitation. `secret_hash` is the sha256
+    /// of a random secret carried by the link; every use counts against the
+    /// operator's quota. The secret is public once first redeemed, so open
+    /// links only grant the associate role; supervisors are invited with
+    /// recipient-bound links.
+    pub fn generate_open_associate_link(env: &Env, secret_hash: BytesN<32>, operator: Address, max_uses: u32, expires_at: Option<u64>) -> Result<(), Error> {
+        Self::authorize_issuer(env, &operator, max_uses)?;
+        Self::new_open_claim(env, secret_hash, ROLE_ASSOCIATE, operator, max_uses, expires_at)
+    }
+
+    /// Revokes an unused claim link. Callable by the admin or the issuer.
+    pub fn revoke_claim_link(env: &Env, token_hash: BytesN<32>, operator: Address) -> Result<(), Error> {
+        operator.require_auth();
+        let mut claim: ClaimData = Self::read_persistent(env, &DataKey::ClaimToken(token_hash.clone()))
+            .ok_or(Error::InvalidClaimToken)?;
+        if Some(operator.clone()) != Self::admin(env) && operator != claim.issuer { return Err(Error::NotAllowed); }
+        match Self::claim_status_at(&claim, env.ledger().timestamp()) {
+            ClaimStatus::Valid => {}
+            ClaimStatus::Used => return Err(Error::TokenAlreadyUsed),
+            ClaimStatus::Revoked => return Err(Error::ClaimRevoked),
+            ClaimStatus::Expired => return Err(Error::ClaimExpired),
+        }
+        claim.status = ClaimStatus::Revoked;
+        Self::write_persistent(env, &DataKey::ClaimToken(token_hash.clone()), &claim);
+        Self::emit_claim_link_revoked(env, claim.role_id, claim.recipient, Self::claim_id(env, &token_hash), operator);
+        Ok(())
+    }
+
+    /// Marks a claim link past its expiry as expired and emits `ClaimExp`.
+    /// Callable by anyone; `claim_nft` rejects expired links on its own, but
+    /// events of a failed call are not recorded.
+    pub fn expire_claim_link(env: &Env, token_hash: BytesN<32>) -> Result<(), Error> {
+        let mut claim: ClaimData = Self::read_persistent(env, &DataKey::ClaimToken(token_hash.clone()))
+            .ok_or(Error::InvalidClaimToken)?;
+        match Self::claim_status_at(&claim, env.ledger().timestamp()) {
+            ClaimStatus::Expired if claim.status == ClaimStatus::Valid => {}
+            ClaimStatus::Used => return Err(Error::TokenAlreadyUsed),
+            ClaimStatus::Revoked => return Err(Error::ClaimRevoked),
+            ClaimStatus::Expired => return Err(Error::ClaimExpired),
+            ClaimStatus::Valid => return Err(Error::InvalidExpiry),
         }
+        claim.status = ClaimStatus::Expired;
+        Self::write_persistent(env, &DataKey::ClaimToken(token_hash.clone()), &claim);
+        Self::emit_claim_link_expired(env, claim.role_id, claim.recipient, Self::claim_id(env, &token_hash));
+        Ok(())
+    }
+
+    pub fn get_claim_status(env: &Env, token_hash: BytesN<32>) -> Result<ClaimStatus, Error> {
+        let claim: ClaimData = Self::read_persistent(env, &DataKey::ClaimToken(token_hash)).ok_or(Error::InvalidClaimToken)?;
+        Ok(Self::claim_status_at(&claim, env.ledger().timestamp()))
+    }
+
+    /// Redeems a recipient-bound claim link. The recipient must authorize,
+    /// so the link can't be redeemed on their behalf by whoever holds it.
+    pub fn claim_nft(env: &Env, token_hash: BytesN<32>, wallet: Address) -> Result<u64, Error> {
+        wallet.require_auth();
+        Self::require_not_paused(env, |p| p.claims)?;
+        Self::enter(env)?;
+        let result = Self::redeem_claim(env, token_hash, wallet, false);
+        Self::exit(env);
+        result
+    }
+
+    /// Redeems an open claim link by presenting the secret whose sha256 was
+    /// registered. The first wallets to present it (up to `max_uses`) win;
+    /// after the first redemption the secret is visible on chain.
+    pub fn claim_nft_with_secret(env: &Env, secret: Bytes, wallet: Address) -> Result<u64, Error> {
+        wallet.require_auth();
+        Self::require_not_paused(env, |p| p.claims)?;
+        Self::enter(env)?;
+        let token_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+        let result = Self::redeem_claim(env, token_hash, wallet, true);
         Self::exit(env);
+        result
+    }
+
+    pub fn revoke_credential(env: &Env, wallet: Address, role_id: u32) -> Result<(), Error> {
+        Self::require_sole_admin(env)?;
+        Self::apply_credential_revocation(env, wallet, role_id)
+    }
+
+    /// Switches `pause`, `unpause`, `set_pause`, `revoke_credential` and
+    /// `set_event_contract` to council mode: afterwards they only run
+    /// through approved proposals. Every member receives `ROLE_ADMIN`.
+    pub fn set_council(env: &Env, members: Vec<Address>, threshold: u32) -> Result<(), Error> {
+        Self::require_sole_admin(env)?;
+        Self::apply_council(env, members, threshold)
+    }
+
+    /// Returns the council members and the approvals a proposal needs.
+    /// An empty set means the single admin acts alone.
+    pub fn get_council(env: &Env) -> (Vec<Address>, u32) {
+        let members = env.storage().instance().get(COUNCIL_KEY).unwrap_or(Vec::new(env));
+        let threshold = env.storage().instance().get(COUNCIL_THRESHOLD_KEY).unwrap_or(0);
+        (members, threshold)
+    }
+
+    /// Opens a proposal for a privileged action. The proposer's approval is
+    /// counted. Returns the proposal id.
+    pub fn propose_action(env: &Env, member: Address, action: AdminAction, expires_at: u64) -> Result<u64, Error> {
+        member.require_auth();
+        Self::require_council_member(env, &member)?;
+        if expires_at <= env.ledger().timestamp() { return Err(Error::InvalidExpiry); }
+        let id: u64 = env.storage().instance().get(NEXT_PROPOSAL_ID_KEY).unwrap_or(1);
+        env.storage().instance().set(NEXT_PROPOSAL_ID_KEY, &(id + 1));
+        let mut approvals = Vec::new(env);
+        approvals.push_back(member.clone());
+        let proposal = CouncilProposal { id, action, proposer: member.clone(), approvals, expires_at, executed: false };
+        Self::write_persistent(env, &DataKey::CouncilProposal(id), &proposal);
+        Self::emit_council_proposed(env, id, member, expires_at);
+        Ok(id)
+    }
+
+    pub fn approve_action(env: &Env, member: Address, proposal_id: u64) -> Result<(), Error> {
+        member.require_auth();
+        Self::require_council_member(env, &member)?;
+        let mut proposal = Self::open_proposal(env, proposal_id)?;
+        if proposal.approvals.contains(&member) { return Err(Error::AlreadyApproved); }
+        proposal.approvals.push_back(member.clone());
+        Self::write_persistent(env, &DataKey::CouncilProposal(proposal_id), &proposal);
+        Self::emit_council_approved(env, proposal_id, member, proposal.approvals.len());
+        Ok(())
+    }
+
+    /// Runs an approved proposal. Only approvals from current members count.
+    /// Callable by anyone.
+    pub fn execute_action(env: &Env, proposal_id: u64) -> Result<(), Error> {
+        let mut proposal = Self::open_proposal(env, proposal_id)?;
+        let (members, threshold) = Self::get_council(env);
+        let approvals = proposal.approvals.iter().filter(|a| members.contains(a)).count() as u32;
+        if threshold == 0 || approvals < threshold { return Err(Error::ThresholdNotMet); }
+        proposal.executed = true;
+        Self::write_persistent(env, &DataKey::CouncilProposal(proposal_id), &proposal);
+        match proposal.action {
+            AdminAction::Pause => Self::apply_pause(env, PauseStatus::all(true)),
+            AdminAction::Unpause => Self::apply_pause(env, PauseStatus::all(false)),
+            AdminAction::SetPause(status) => Self::apply_pause(env, status),
+            AdminAction::RevokeCredential(wallet, role_id) => Self::apply_credential_revocation(env, wallet, role_id)?,
+            AdminAction::SetEventContract(contract_id) => Self::apply_event_contract(env, contract_id),
+            AdminAction::SetCouncil(members, threshold) => Self::apply_council(env, members, threshold)?,
+            AdminAction::DissolveCouncil => Self::apply_council(env, Vec::new(env), 0)?,
+        }
+        Self::emit_council_executed(env, proposal_id);
+        Ok(())
+    }
+
+    pub fn get_action(env: &Env, proposal_id: u64) -> Option<CouncilProposal> {
+        Self::read_persistent(env, &DataKey::CouncilProposal(proposal_id))
+    }
+
+    /// Extends the TTL of `wallet`'s role map and credential tokens so
+    /// archivers can keep them alive. Callable by anyone.
+    pub fn bump_credential(env: &Env, wallet: Address) -> Result<(), Error> {
+        let roles: Map<u32, u64> = Self::read_persistent(env, &DataKey::RoleByWallet(wallet))
+            .ok_or(Error::CredentialNotFound)?;
+        for token_id in roles.values() {
+            Self::read_persistent::<TokenData>(env, &DataKey::TokenById(token_id));
+        }
+        Self::bump_instance(env);
+        Ok(())
     }
 
     pub fn has_role(env: &Env, wallet: Address, role_id: u32) -> bool {
@@ -207,7 +670,9 @@ impl OwnerRules {
         true
     }
 
-    fn new_claim(env: &Env, recipient: Address, role_id: u32) -> BytesN<32> {
+    fn new_claim(env: &Env, recipient: Address, role_id: u32, issuer: Address, expires_at: Option<u64>) -> Result<BytesN<32>, Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        let expires_at = Self::claim_expiry(env, expires_at)?;
         let r: u64 = env.prng().gen_range(1..=u64::MAX);
         let mut bytes = Bytes::new(env);
         let _ = recipient;
@@ -216,26 +681,270 @@ impl OwnerRules {
         let role_bytes = role_id.to_be_bytes();
         bytes.extend_from_slice(&role_bytes);
         let hash: BytesN<32> = env.crypto().sha256(&bytes).into();
-        env.storage().instance().set(
+        Self::write_persistent(
+            env,
             &DataKey::ClaimToken(hash.clone()),
-            &ClaimData { role_id, recipient: recipient.clone(), valid: true },
+            &ClaimData {
+                role_id,
+                recipient: Some(recipient.clone()),
+                issuer: issuer.clone(),
+                max_uses: 1,
+                uses: 0,
+                expires_at,
+                status: ClaimStatus::Valid,
+            },
         );
-        Self::emit_claim_link_generated(env, recipient, role_id, hash.clone());
-        hash
+        Self::emit_claim_link_generated(env, recipient, role_id, Self::claim_id(env, &hash), issuer);
+        Ok(hash)
+    }
+
+    fn new_open_claim(env: &Env, secret_hash: BytesN<32>, role_id: u32, issuer: Address, max_uses: u32, expires_at: Option<u64>) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        if max_uses == 0 { return Err(Error::InvalidMaxUses); }
+        let expires_at = Self::claim_expiry(env, expires_at)?;
+        let key = DataKey::ClaimToken(secret_hash.clone());
+        if env.storage().persistent().has(&key) { return Err(Error::DuplicateClaim); }
+        let claim = ClaimData { role_id, recipient: None, issuer, max_uses, uses: 0, expires_at, status: ClaimStatus::Valid };
+        Self::write_persistent(env, &key, &claim);
+        Self::emit_open_claim_link_generated(env, role_id, claim.issuer, secret_hash, max_uses, expires_at);
+        Ok(())
+    }
+
+    /// Public identifier of a claim link. Events carry this instead of the
+    /// token hash itself, which is the redemption secret.
+    fn claim_id(env: &Env, token_hash: &BytesN<32>) -> BytesN<32> {
+        env.crypto().sha256(&Bytes::from(token_hash)).into()
+    }
+
+    /// Authorizes `operator` to issue `uses` associate claims. The admin is
+    /// unrestricted; supervisors are charged against their quota.
+    fn authorize_issuer(env: &Env, operator: &Address, uses: u32) -> Result<(), Error> {
+        operator.require_auth();
+        if Self::admin(env).as_ref() == Some(operator) { return Ok(()); }
+        if !Self::has_role(env, operator.clone(), ROLE_SUPERVISOR) { return Err(Error::NotAllowed); }
+        let key = DataKey::IssuedCount(operator.clone());
+        let issued: u32 = Self::read_persistent(env, &key).unwrap_or(0);
+        let issued = issued.checked_add(uses).ok_or(Error::QuotaExceeded)?;
+        if issued > Self::issuance_quota_for(env, operator) { return Err(Error::QuotaExceeded); }
+        Self::write_persistent(env, &key, &issued);
+        Ok(())
+    }
+
+    fn issuance_quota_for(env: &Env, supervisor: &Address) -> u32 {
+        Self::read_persistent(env, &DataKey::IssuanceQuota(supervisor.clone())).unwrap_or_else(|| Self::get_default_issuance_quota(env))
+    }
+
+    fn claim_expiry(env: &Env, expires_at: Option<u64>) -> Result<u64, Error> {
+        let now = env.ledger().timestamp();
+        match expires_at {
+            Some(ts) if ts <= now => Err(Error::InvalidExpiry),
+            Some(ts) => Ok(ts),
+            None => Ok(now.saturating_add(Self::get_default_claim_ttl(env))),
+        }
+    }
+
+    /// Mints the claimed role to `wallet`. Recipient-bound links can only be
+    /// redeemed by their recipient; open links only with the secret.
+    fn redeem_claim(env: &Env, token_hash: BytesN<32>, wallet: Address, with_secret: bool) -> Result<u64, Error> {
+        let mut claim: ClaimData = Self::read_persistent(env, &DataKey::ClaimToken(token_hash.clone()))
+            .ok_or(Error::InvalidClaimToken)?;
+        match Self::claim_status_at(&claim, env.ledger().timestamp()) {
+            ClaimStatus::Valid => {}
+            ClaimStatus::Used => return Err(Error::TokenAlreadyUsed),
+            ClaimStatus::Revoked => return Err(Error::ClaimRevoked),
+            ClaimStatus::Expired => return Err(Error::ClaimExpired),
+        }
+        match &claim.recipient {
+            Some(recipient) => if *recipient != wallet { return Err(Error::RecipientMismatch); },
+            None => if !with_secret { return Err(Error::SecretRequired); },
+        }
+        if Self::role_token_for(env, &wallet, claim.role_id).is_some() { return Err(Error::AlreadyHasRole); }
+        let token_id = Self::next_id_and_increment(env);
+        let data = TokenData { role_id: claim.role_id, owner: wallet.clone() };
+        Self::write_persistent(env, &DataKey::TokenById(token_id), &data);
+        let mut roles: Map<u32, u64> = Self::roles_for(env, &wallet);
+        roles.set(claim.role_id, token_id);
+        Self::write_persistent(env, &DataKey::RoleByWallet(wallet.clone()), &roles);
+        claim.uses += 1;
+        if claim.uses >= claim.max_uses { claim.status = ClaimStatus::Used; }
+        Self::write_persistent(env, &DataKey::ClaimToken(token_hash), &claim);
+        Self::emit_credential_minted(env, claim.role_id, wallet, token_id);
+        Ok(token_id)
+    }
+
+    fn claim_status_at(claim: &ClaimData, now: u64) -> ClaimStatus {
+        if claim.status == ClaimStatus::Valid && now > claim.expires_at { return ClaimStatus::Expired; }
+        claim.status
     }
 
     fn admin(env: &Env) -> Option<Address> {
         env.storage().instance().get(ADMIN_KEY)
     }
 
-    fn require_admin(env: &Env) {
-        let admin = Self::admin(env).expect("admin not set");
+    /// Moves the admin slot and its `ROLE_ADMIN` credential to `new_admin`,
+    /// dropping any handover that was still pending.
+    fn set_admin(env: &Env, new_admin: Address) {
+        env.storage().instance().remove(PENDING_ADMIN_KEY);
+        let old_admin = Self::admin(env);
+        if let Some(old_admin) = old_admin.clone()
+            && !Self::is_council_member(env, &old_admin)
+            && let Some(old_token) = Self::role_token_for(env, &old_admin, ROLE_ADMIN)
+        {
+            Self::remove_persistent(env, &DataKey::TokenById(old_token));
+            let mut roles_old: Map<u32, u64> = Self::roles_for(env, &old_admin);
+            roles_old.remove(ROLE_ADMIN);
+            Self::write_persistent(env, &DataKey::RoleByWallet(old_admin.clone()), &roles_old);
+            Self::emit_credential_revoked(env, ROLE_ADMIN, old_admin.clone(), old_token);
+        }
+        env.storage().instance().set(ADMIN_KEY, &new_admin);
+        let token_id = Self::next_id_and_increment(env);
+        let data = TokenData { role_id: ROLE_ADMIN, owner: new_admin.clone() };
+        Self::write_persistent(env, &DataKey::TokenById(token_id), &data);
+        let mut roles_new: Map<u32, u64> = Self::roles_for(env, &new_admin);
+        roles_new.set(ROLE_ADMIN, token_id);
+        Self::write_persistent(env, &DataKey::RoleByWallet(new_admin.clone()), &roles_new);
+        if let Some(old_admin) = old_admin {
+            Self::emit_admin_transferred(env, old_admin, new_admin.clone());
+        }
+        Self::emit_credential_minted(env, ROLE_ADMIN, new_admin, token_id);
+    }
+
+    fn recovery(env: &Env) -> Option<RecoveryProposal> {
+        env.storage().instance().get(RECOVERY_KEY)
+    }
+
+    fn require_guardian(env: &Env, guardian: &Address) -> Result<(), Error> {
+        let (guardians, _) = Self::get_guardians(env);
+        if !guardians.contains(guardian) { return Err(Error::NotGuardian); }
+        Ok(())
+    }
+
+    /// Records an approval and starts the timelock when it meets the threshold.
+    fn add_recovery_approval(env: &Env, proposal: &mut RecoveryProposal, guardian: Address) {
+        proposal.approvals.push_back(guardian.clone());
+        Self::emit_recovery_approved(env, guardian, proposal.approvals.len());
+        let (_, threshold) = Self::get_guardians(env);
+        if proposal.approvals.len() >= threshold {
+            let (_, delay) = Self::get_recovery_timing(env);
+            let executable_at = env.ledger().timestamp().saturating_add(delay);
+            proposal.executable_at = Some(executable_at);
+            Self::emit_recovery_ready(env, proposal.new_admin.clone(), executable_at);
+        }
+    }
+
+    /// Admin check for actions the council takes over once it is set.
+    fn require_sole_admin(env: &Env) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        if env.storage().instance().has(COUNCIL_KEY) { return Err(Error::CouncilRequired); }
+        Ok(())
+    }
+
+    fn is_council_member(env: &Env, wallet: &Address) -> bool {
+        let (members, _) = Self::get_council(env);
+        members.contains(wallet)
+    }
+
+    fn require_council_member(env: &Env, wallet: &Address) -> Result<(), Error> {
+        if !Self::is_council_member(env, wallet) { return Err(Error::NotCouncilMember); }
+        Ok(())
+    }
+
+    fn open_proposal(env: &Env, proposal_id: u64) -> Result<CouncilProposal, Error> {
+        let proposal: CouncilProposal = Self::read_persistent(env, &DataKey::CouncilProposal(proposal_id))
+            .ok_or(Error::ProposalNotFound)?;
+        if proposal.executed { return Err(Error::ProposalExecuted); }
+        if env.ledger().timestamp() > proposal.expires_at { return Err(Error::ProposalExpired); }
+        Ok(proposal)
+    }
+
+    /// Replaces the council. An empty set with threshold 0 dissolves it.
+    /// New members get `ROLE_ADMIN`; departing ones lose it unless they
+    /// hold the admin slot.
+    fn apply_council(env: &Env, members: Vec<Address>, threshold: u32) -> Result<(), Error> {
+        if members.is_empty() {
+            if threshold != 0 { return Err(Error::InvalidThreshold); }
+        } else if threshold == 0 || threshold > members.len() {
+            return Err(Error::InvalidThreshold);
+        }
+        for (i, m) in members.iter().enumerate() {
+            if members.iter().skip(i + 1).any(|other| other == m) { return Err(Error::InvalidCouncil); }
+        }
+        let (previous, _) = Self::get_council(env);
+        let admin = Self::admin(env);
+        for old in previous.iter() {
+            if !members.contains(&old) && admin.as_ref() != Some(&old) {
+                Self::burn_credential(env, &old, ROLE_ADMIN);
+            }
+        }
+        for member in members.iter() {
+            if Self::role_token_for(env, &member, ROLE_ADMIN).is_none() {
+                Self::mint_credential(env, &member, ROLE_ADMIN);
+            }
+        }
+        if members.is_empty() {
+            env.storage().instance().remove(COUNCIL_KEY);
+            env.storage().instance().remove(COUNCIL_THRESHOLD_KEY);
+        } else {
+            env.storage().instance().set(COUNCIL_KEY, &members);
+            env.storage().instance().set(COUNCIL_THRESHOLD_KEY, &threshold);
+        }
+        Self::emit_council_set(env, members, threshold);
+        Ok(())
+    }
+
+    fn apply_event_contract(env: &Env, contract_id: String) {
+        env.storage().instance().set(EVENT_CONTRACT_KEY, &contract_id);
+        Self::emit_event_contract_updated(env, contract_id);
+    }
+
+    fn apply_credential_revocation(env: &Env, wallet: Address, role_id: u32) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        Self::enter(env)?;
+        let burned = Self::burn_credential(env, &wallet, role_id);
+        Self::exit(env);
+        burned.map(|_| ()).ok_or(Error::CredentialNotFound)
+    }
+
+    fn mint_credential(env: &Env, wallet: &Address, role_id: u32) -> u64 {
+        let token_id = Self::next_id_and_increment(env);
+        let data = TokenData { role_id, owner: wallet.clone() };
+        Self::write_persistent(env, &DataKey::TokenById(token_id), &data);
+        let mut roles: Map<u32, u64> = Self::roles_for(env, wallet);
+        roles.set(role_id, token_id);
+        Self::write_persistent(env, &DataKey::RoleByWallet(wallet.clone()), &roles);
+        Self::emit_credential_minted(env, role_id, wallet.clone(), token_id);
+        token_id
+    }
+
+    fn burn_credential(env: &Env, wallet: &Address, role_id: u32) -> Option<u64> {
+        let token_id = Self::role_token_for(env, wallet, role_id)?;
+        Self::remove_persistent(env, &DataKey::TokenById(token_id));
+        let mut roles: Map<u32, u64> = Self::roles_for(env, wallet);
+        roles.remove(role_id);
+        Self::write_persistent(env, &DataKey::RoleByWallet(wallet.clone()), &roles);
+        Self::emit_credential_revoked(env, role_id, wallet.clone(), token_id);
+        Some(token_id)
+    }
+
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin = Self::admin(env).ok_or(Error::AdminNotSet)?;
         admin.require_auth();
+        Ok(())
+    }
+
+    fn require_not_paused(env: &Env, flag: fn(&PauseStatus) -> bool) -> Result<(), Error> {
+        if flag(&Self::pause_status(env)) { return Err(Error::Paused); }
+        Ok(())
     }
 
-    fn require_not_paused(env: &Env) {
-        let paused: bool = env.storage().instance().get(PAUSED_KEY).unwrap_or(false);
-        if paused { panic!("paused"); }
+    /// Stores `status` and reports which flags it raised and lowered.
+    fn apply_pause(env: &Env, status: PauseStatus) {
+        let previous = Self::pause_status(env);
+        env.storage().instance().set(PAUSE_FLAGS_KEY, &status);
+        let raised = status.without(&previous);
+        let lowered = previous.without(&status);
+        if raised.any() { Self::emit_paused(env, raised); }
+        if lowered.any() { Self::emit_unpaused(env, lowered); }
     }
 
     fn next_id_and_increment(env: &Env) -> u64 {
@@ -249,10 +958,7 @@ impl OwnerRules {
     }
 
     fn roles_for(env: &Env, wallet: &Address) -> Map<u32, u64> {
-        env.storage()
-            .instance()
-            .get(&DataKey::RoleByWallet(wallet.clone()))
-            .unwrap_or(Map::new(env))
+        Self::read_persistent(env, &DataKey::RoleByWallet(wallet.clone())).unwrap_or(Map::new(env))
     }
 
     fn role_token_for(env: &Env, wallet: &Address, role_id: u32) -> Option<u64> {
@@ -260,16 +966,41 @@ impl OwnerRules {
         roles.get(role_id)
     }
 
-    fn enter(env: &Env) {
+    fn enter(env: &Env) -> Result<(), Error> {
         let locked: bool = env.storage().instance().get(LOCKED_KEY).unwrap_or(false);
-        if locked { panic!("reentrancy"); }
+        if locked { return Err(Error::Reentrancy); }
         env.storage().instance().set(LOCKED_KEY, &true);
+        Ok(())
     }
 
     fn exit(env: &Env) {
         env.storage().instance().set(LOCKED_KEY, &false);
     }
 
+    fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() { Self::bump_persistent(env, key); }
+        value
+    }
+
+    fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::bump_persistent(env, key);
+        Self::bump_instance(env);
+    }
+
+    fn remove_persistent(env: &Env, key: &DataKey) {
+        env.storage().persistent().remove(key);
+    }
+
+    fn bump_persistent(env: &Env, key: &DataKey) {
+        env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn bump_instance(env: &Env) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
     fn emit_credential_minted(env: &Env, role_id: u32, recipient: Address, token_id: u64) {
         env.events().publish((symbol_short!("CredMint"), role_id, recipient.clone()), token_id);
     }
@@ -279,15 +1010,95 @@ impl OwnerRules {
     }
 
     fn emit_admin_transferred(env: &Env, old_admin: Address, new_admin: Address) {
-        env.events().publish((symbol_short!("AdminXfer"), old_admin, new_admin), true);
+        env.events().publish((symbol_short!("AdminXfer"), old_admin, new_admin), (EVENT_VERSION,));
+    }
+
+    fn emit_admin_proposed(env: &Env, admin: Address, new_admin: Address, expires_at: u64) {
+        env.events().publish((symbol_short!("AdmProp"), admin, new_admin), (EVENT_VERSION, expires_at));
+    }
+
+    fn emit_admin_proposal_cancelled(env: &Env, new_admin: Address) {
+        env.events().publish((symbol_short!("AdmCancel"), new_admin), (EVENT_VERSION,));
     }
 
     fn emit_base_uri_updated(env: &Env, new_uri: String) {
         env.events().publish((symbol_short!("BaseURI"),), new_uri);
     }
 
-    fn emit_claim_link_generated(env: &Env, recipient: Address, role_id: u32, claim_hash: BytesN<32>) {
-        env.events().publish((symbol_short!("ClaimGen"), role_id, recipient), claim_hash);
+    fn emit_claim_link_generated(env: &Env, recipient: Address, role_id: u32, claim_id: BytesN<32>, issuer: Address) {
+        env.events().publish((symbol_short!("ClaimGen"), role_id, recipient), (claim_id, issuer));
+    }
+
+    fn emit_guardians_set(env: &Env, guardians: Vec<Address>, threshold: u32) {
+        env.events().publish((symbol_short!("GuardSet"),), (guardians, threshold));
+    }
+
+    fn emit_recovery_timing(env: &Env, approval_window_secs: u64, timelock_secs: u64) {
+        env.events().publish((symbol_short!("RecTiming"),), (approval_window_secs, timelock_secs));
+    }
+
+    fn emit_recovery_proposed(env: &Env, proposer: Address, new_admin: Address, approve_by: u64) {
+        env.events().publish((symbol_short!("RecProp"), proposer, new_admin), approve_by);
+    }
+
+    fn emit_recovery_approved(env: &Env, guardian: Address, approvals: u32) {
+        env.events().publish((symbol_short!("RecApprov"), guardian), approvals);
+    }
+
+    fn emit_recovery_ready(env: &Env, new_admin: Address, executable_at: u64) {
+        env.events().publish((symbol_short!("RecReady"), new_admin), executable_at);
+    }
+
+    fn emit_recovery_vetoed(env: &Env, proposer: Address, new_admin: Address) {
+        env.events().publish((symbol_short!("RecVeto"), proposer, new_admin), true);
+    }
+
+    fn emit_recovery_executed(env: &Env, new_admin: Address, approvals: Vec<Address>) {
+        env.events().publish((symbol_short!("RecExec"), new_admin), approvals);
+    }
+
+    fn emit_paused(env: &Env, flags: PauseStatus) {
+        env.events().publish((symbol_short!("Paused"),), (EVENT_VERSION, flags));
+    }
+
+    fn emit_unpaused(env: &Env, flags: PauseStatus) {
+        env.events().publish((symbol_short!("Unpaused"),), (EVENT_VERSION, flags));
+    }
+
+    fn emit_council_set(env: &Env, members: Vec<Address>, threshold: u32) {
+        env.events().publish((symbol_short!("CnclSet"),), (members, threshold));
+    }
+
+    fn emit_council_proposed(env: &Env, proposal_id: u64, proposer: Address, expires_at: u64) {
+        env.events().publish((symbol_short!("CnclProp"), proposal_id, proposer), expires_at);
+    }
+
+    fn emit_council_approved(env: &Env, proposal_id: u64, member: Address, approvals: u32) {
+        env.events().publish((symbol_short!("CnclAppr"), proposal_id, member), approvals);
+    }
+
+    fn emit_council_executed(env: &Env, proposal_id: u64) {
+        env.events().publish((symbol_short!("CnclExec"), proposal_id), true);
+    }
+
+    fn emit_default_claim_ttl_set(env: &Env, ttl_secs: u64) {
+        env.events().publish((symbol_short!("ClaimTTL"),), ttl_secs);
+    }
+
+    fn emit_issuance_quota_set(env: &Env, supervisor: Option<Address>, quota: u32) {
+        env.events().publish((symbol_short!("IssQuota"), supervisor), quota);
+    }
+
+    fn emit_open_claim_link_generated(env: &Env, role_id: u32, issuer: Address, secret_hash: BytesN<32>, max_uses: u32, expires_at: u64) {
+        env.events().publish((symbol_short!("ClaimOpen"), role_id, issuer), (secret_hash, max_uses, expires_at));
+    }
+
+    fn emit_claim_link_revoked(env: &Env, role_id: u32, recipient: Option<Address>, claim_id: BytesN<32>, operator: Address) {
+        env.events().publish((symbol_short!("ClaimRev"), role_id, recipient), (claim_id, operator));
+    }
+
+    fn emit_claim_link_expired(env: &Env, role_id: u32, recipient: Option<Address>, claim_id: BytesN<32>) {
+        env.events().publish((symbol_short!("ClaimExp"), role_id, recipient), claim_id);
     }
 
     fn emit_event_contract_updated(env: &Env, contract_id: String) {
@@ -0,0 +1,74 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, IntoVal, Val, Vec};
+
+use super::{advance, emitted, setup, NOW};
+use crate::contract::EVENT_VERSION;
+use crate::Error;
+
+#[test]
+fn already_initialized() {
+    let (_, client, admin) = setup();
+    assert_eq!(client.try_initialize(&admin), Err(Ok(Error::AlreadyInitialized)));
+}
+
+#[test]
+fn admin_not_set() {
+    let (env, client, _) = setup();
+    env.as_contract(&client.address, || env.storage().instance().remove(&symbol_short!("ADMIN")));
+    assert_eq!(client.try_get_admin(), Err(Ok(Error::AdminNotSet)));
+}
+
+#[test]
+fn no_pending_admin() {
+    let (_, client, _) = setup();
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+}
+
+#[test]
+fn admin_proposal_expired() {
+    let (env, client, _) = setup();
+    client.propose_admin(&Address::generate(&env), &(NOW + 10));
+    advance(&env, 11);
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::AdminProposalExpired)));
+}
+
+#[test]
+fn admin_handover_emits_versioned_events() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin, &(NOW + 10));
+    let events = emitted(&env, &client);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("AdmProp"), admin.clone(), new_admin.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let data: (u32, u64) = data.into_val(&env);
+    assert_eq!(data, (EVENT_VERSION, NOW + 10));
+
+    // The handover revokes the old admin role, then publishes the transfer
+    // before minting the new one.
+    client.accept_admin();
+    let events = emitted(&env, &client);
+    assert_eq!(events.len(), 3);
+    let (topics, data) = events[1].clone();
+    let expected: Vec<Val> = (symbol_short!("AdminXfer"), admin, new_admin.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let data: (u32,) = data.into_val(&env);
+    assert_eq!(data, (EVENT_VERSION,));
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn admin_proposal_cancel_emits_versioned_event() {
+    let (env, client, _) = setup();
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin, &(NOW + 10));
+    client.cancel_admin_proposal();
+    let events = emitted(&env, &client);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("AdmCancel"), new_admin).into_val(&env);
+    assert_eq!(topics, expected);
+    let data: (u32,) = data.into_val(&env);
+    assert_eq!(data, (EVENT_VERSION,));
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+}
@@ -0,0 +1,249 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    Address, Bytes, BytesN, IntoVal, InvokeError, Val, Vec,
+};
+
+use super::{advance, emitted, mint_supervisor, setup, NOW};
+use crate::contract::{DAY_IN_LEDGERS, PERSISTENT_BUMP_AMOUNT};
+use crate::{ClaimStatus, DataKey, Error};
+
+#[test]
+fn reentrancy() {
+    let (env, client, _) = setup();
+    env.as_contract(&client.address, || env.storage().instance().set(&symbol_short!("LOCKED"), &true));
+    let token = BytesN::from_array(&env, &[1; 32]);
+    assert_eq!(client.try_claim_nft(&token, &Address::generate(&env)), Err(Ok(Error::Reentrancy)));
+}
+
+#[test]
+fn invalid_claim_token() {
+    let (env, client, _) = setup();
+    let token = BytesN::from_array(&env, &[1; 32]);
+    assert_eq!(client.try_get_claim_status(&token), Err(Ok(Error::InvalidClaimToken)));
+}
+
+#[test]
+fn token_already_used() {
+    let (env, client, _) = setup();
+    let wallet = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&wallet, &None);
+    client.claim_nft(&token, &wallet);
+    assert_eq!(client.try_claim_nft(&token, &wallet), Err(Ok(Error::TokenAlreadyUsed)));
+}
+
+#[test]
+fn recipient_mismatch() {
+    let (env, client, _) = setup();
+    let token = client.generate_supervisor_claim_link(&Address::generate(&env), &None);
+    assert_eq!(client.try_claim_nft(&token, &Address::generate(&env)), Err(Ok(Error::RecipientMismatch)));
+}
+
+#[test]
+fn already_has_role() {
+    let (env, client, _) = setup();
+    let wallet = Address::generate(&env);
+    mint_supervisor(&client, &wallet);
+    let token = client.generate_supervisor_claim_link(&wallet, &None);
+    assert_eq!(client.try_claim_nft(&token, &wallet), Err(Ok(Error::AlreadyHasRole)));
+}
+
+#[test]
+fn credential_not_found() {
+    let (env, client, _) = setup();
+    assert_eq!(client.try_revoke_credential(&Address::generate(&env), &2), Err(Ok(Error::CredentialNotFound)));
+}
+
+#[test]
+fn not_allowed() {
+    let (env, client, _) = setup();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_generate_associate_claim_link(&Address::generate(&env), &stranger, &None),
+        Err(Ok(Error::NotAllowed))
+    );
+}
+
+#[test]
+fn claim_revoked() {
+    let (env, client, admin) = setup();
+    let wallet = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&wallet, &None);
+    client.revoke_claim_link(&token, &admin);
+    assert_eq!(client.try_claim_nft(&token, &wallet), Err(Ok(Error::ClaimRevoked)));
+}
+
+#[test]
+fn claim_expired() {
+    let (env, client, _) = setup();
+    let wallet = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&wallet, &Some(NOW + 10));
+    advance(&env, 11);
+    assert_eq!(client.try_claim_nft(&token, &wallet), Err(Ok(Error::ClaimExpired)));
+}
+
+#[test]
+fn invalid_expiry() {
+    let (env, client, _) = setup();
+    assert_eq!(
+        client.try_generate_supervisor_claim_link(&Address::generate(&env), &Some(NOW)),
+        Err(Ok(Error::InvalidExpiry))
+    );
+}
+
+#[test]
+fn secret_required() {
+    let (env, client, admin) = setup();
+    let secret_hash = BytesN::from_array(&env, &[2; 32]);
+    client.generate_open_associate_link(&secret_hash, &admin, &1, &None);
+    assert_eq!(client.try_claim_nft(&secret_hash, &Address::generate(&env)), Err(Ok(Error::SecretRequired)));
+}
+
+#[test]
+fn invalid_max_uses() {
+    let (env, client, admin) = setup();
+    let secret_hash = BytesN::from_array(&env, &[2; 32]);
+    assert_eq!(
+        client.try_generate_open_associate_link(&secret_hash, &admin, &0, &None),
+        Err(Ok(Error::InvalidMaxUses))
+    );
+}
+
+#[test]
+fn duplicate_claim() {
+    let (env, client, admin) = setup();
+    let secret_hash = BytesN::from_array(&env, &[2; 32]);
+    client.generate_open_associate_link(&secret_hash, &admin, &1, &None);
+    assert_eq!(
+        client.try_generate_open_associate_link(&secret_hash, &admin, &1, &None),
+        Err(Ok(Error::DuplicateClaim))
+    );
+}
+
+#[test]
+fn open_link_admits_wallets_up_to_max_uses() {
+    let (env, client, admin) = setup();
+    let secret = Bytes::from_array(&env, &[7; 16]);
+    let secret_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+    client.generate_open_associate_link(&secret_hash, &admin, &2, &None);
+    let (w1, w2, w3) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    client.claim_nft_with_secret(&secret, &w1);
+    assert_eq!(client.get_claim_status(&secret_hash), ClaimStatus::Valid);
+    client.claim_nft_with_secret(&secret, &w2);
+    assert!(client.has_role(&w1, &3));
+    assert!(client.has_role(&w2, &3));
+    assert_eq!(client.get_claim_status(&secret_hash), ClaimStatus::Used);
+    assert_eq!(client.try_claim_nft_with_secret(&secret, &w3), Err(Ok(Error::TokenAlreadyUsed)));
+}
+
+#[test]
+fn open_link_requires_the_matching_secret_once_per_wallet() {
+    let (env, client, admin) = setup();
+    let secret = Bytes::from_array(&env, &[7; 16]);
+    let secret_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+    client.generate_open_associate_link(&secret_hash, &admin, &2, &None);
+    let wallet = Address::generate(&env);
+    assert_eq!(
+        client.try_claim_nft_with_secret(&Bytes::from_array(&env, &[8; 16]), &wallet),
+        Err(Ok(Error::InvalidClaimToken))
+    );
+    client.claim_nft_with_secret(&secret, &wallet);
+    assert_eq!(client.try_claim_nft_with_secret(&secret, &wallet), Err(Ok(Error::AlreadyHasRole)));
+}
+
+#[test]
+fn quota_exceeded() {
+    let (env, client, _) = setup();
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&client, &supervisor);
+    client.set_issuance_quota(&supervisor, &0);
+    assert_eq!(
+        client.try_generate_associate_claim_link(&Address::generate(&env), &supervisor, &None),
+        Err(Ok(Error::QuotaExceeded))
+    );
+}
+
+#[test]
+fn supervisor_issues_associate_links_within_quota() {
+    let (env, client, admin) = setup();
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&client, &supervisor);
+    client.set_issuance_quota(&supervisor, &2);
+    let recipient = Address::generate(&env);
+    let token = client.generate_associate_claim_link(&recipient, &supervisor, &None);
+    let (topics, data) = emitted(&env, &client)[0].clone();
+    let expected: Vec<Val> = (symbol_short!("ClaimGen"), 3u32, recipient.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let claim_id: BytesN<32> = env.crypto().sha256(&token.clone().into()).into();
+    let data: (BytesN<32>, Address) = data.into_val(&env);
+    assert_eq!(data, (claim_id, supervisor.clone()));
+    assert_eq!(client.get_issuance_quota(&supervisor), (2, 1));
+
+    client.generate_open_associate_link(&BytesN::from_array(&env, &[2; 32]), &supervisor, &1, &None);
+    assert_eq!(client.get_issuance_quota(&supervisor), (2, 2));
+    assert_eq!(
+        client.try_generate_associate_claim_link(&Address::generate(&env), &supervisor, &None),
+        Err(Ok(Error::QuotaExceeded))
+    );
+    client.generate_associate_claim_link(&Address::generate(&env), &admin, &None);
+    client.claim_nft(&token, &recipient);
+    assert!(client.has_role(&recipient, &3));
+}
+
+#[test]
+fn associate_link_requires_operator_auth() {
+    let (env, client, _) = setup();
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&client, &supervisor);
+    env.set_auths(&[]);
+    assert_eq!(
+        client.try_generate_associate_claim_link(&Address::generate(&env), &supervisor, &None).err(),
+        Some(Err(InvokeError::Abort))
+    );
+    assert_eq!(client.get_issuance_quota(&supervisor).1, 0);
+}
+
+#[test]
+fn claim_nft_rejects_another_wallet() {
+    let (env, client, _) = setup();
+    let (recipient, other) = (Address::generate(&env), Address::generate(&env));
+    let token = client.generate_supervisor_claim_link(&recipient, &None);
+    assert_eq!(client.try_claim_nft(&token, &other), Err(Ok(Error::RecipientMismatch)));
+    assert!(!client.has_role(&other, &2));
+    assert_eq!(client.get_claim_status(&token), ClaimStatus::Valid);
+    client.claim_nft(&token, &recipient);
+    assert!(client.has_role(&recipient, &2));
+}
+
+#[test]
+fn claim_nft_requires_recipient_auth() {
+    let (env, client, _) = setup();
+    let recipient = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&recipient, &None);
+    env.set_auths(&[]);
+    assert_eq!(client.try_claim_nft(&token, &recipient), Err(Err(InvokeError::Abort)));
+    assert!(!client.has_role(&recipient, &2));
+    assert_eq!(client.get_claim_status(&token), ClaimStatus::Valid);
+}
+
+#[test]
+fn bump_credential_renews_roles_and_tokens() {
+    let (env, client, _) = setup();
+    let wallet = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&wallet, &None);
+    let token_id = client.claim_nft(&token, &wallet);
+    let ttl = |key: DataKey| env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+    assert!(!env.as_contract(&client.address, || env.storage().instance().has(&DataKey::TokenById(token_id))));
+
+    env.ledger().with_mut(|l| l.sequence_number += 20 * DAY_IN_LEDGERS);
+    assert_eq!(ttl(DataKey::TokenById(token_id)), PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS);
+    client.bump_credential(&wallet);
+    assert_eq!(ttl(DataKey::RoleByWallet(wallet.clone())), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::TokenById(token_id)), PERSISTENT_BUMP_AMOUNT);
+}
+
+#[test]
+fn bump_credential_requires_a_credential() {
+    let (env, client, _) = setup();
+    assert_eq!(client.try_bump_credential(&Address::generate(&env)), Err(Ok(Error::CredentialNotFound)));
+}
@@ -0,0 +1,125 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use super::{advance, setup, NOW};
+use crate::{AdminAction, Error};
+
+#[test]
+fn council_required() {
+    let (env, client, _) = setup();
+    client.set_council(&vec![&env, Address::generate(&env)], &1);
+    assert_eq!(client.try_pause(), Err(Ok(Error::CouncilRequired)));
+}
+
+#[test]
+fn not_council_member() {
+    let (env, client, _) = setup();
+    assert_eq!(
+        client.try_propose_action(&Address::generate(&env), &AdminAction::Pause, &(NOW + 10)),
+        Err(Ok(Error::NotCouncilMember))
+    );
+}
+
+#[test]
+fn invalid_council() {
+    let (env, client, _) = setup();
+    let m = Address::generate(&env);
+    assert_eq!(client.try_set_council(&vec![&env, m.clone(), m], &1), Err(Ok(Error::InvalidCouncil)));
+}
+
+#[test]
+fn proposal_not_found() {
+    let (_, client, _) = setup();
+    assert_eq!(client.try_execute_action(&99), Err(Ok(Error::ProposalNotFound)));
+}
+
+#[test]
+fn proposal_expired() {
+    let (env, client, _) = setup();
+    let (m1, m2) = (Address::generate(&env), Address::generate(&env));
+    client.set_council(&vec![&env, m1.clone(), m2.clone()], &2);
+    let id = client.propose_action(&m1, &AdminAction::Pause, &(NOW + 10));
+    advance(&env, 11);
+    assert_eq!(client.try_approve_action(&m2, &id), Err(Ok(Error::ProposalExpired)));
+}
+
+#[test]
+fn proposal_executed() {
+    let (env, client, _) = setup();
+    let m = Address::generate(&env);
+    client.set_council(&vec![&env, m.clone()], &1);
+    let id = client.propose_action(&m, &AdminAction::Pause, &(NOW + 10));
+    client.execute_action(&id);
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::ProposalExecuted)));
+}
+
+#[test]
+fn threshold_not_met() {
+    let (env, client, _) = setup();
+    let (m1, m2) = (Address::generate(&env), Address::generate(&env));
+    client.set_council(&vec![&env, m1.clone(), m2], &2);
+    let id = client.propose_action(&m1, &AdminAction::Pause, &(NOW + 10));
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::ThresholdNotMet)));
+}
+
+#[test]
+fn action_runs_once_approvals_reach_the_threshold() {
+    let (env, client, _) = setup();
+    let (m1, m2, m3) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    client.set_council(&vec![&env, m1.clone(), m2.clone(), m3], &2);
+    let id = client.propose_action(&m1, &AdminAction::Pause, &(NOW + 10));
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::ThresholdNotMet)));
+    assert!(!client.pause_status().claims);
+
+    client.approve_action(&m2, &id);
+    client.execute_action(&id);
+    assert!(client.pause_status().claims);
+    assert!(client.get_action(&id).unwrap().executed);
+}
+
+#[test]
+fn repeated_approval_does_not_count_twice() {
+    let (env, client, _) = setup();
+    let (m1, m2) = (Address::generate(&env), Address::generate(&env));
+    client.set_council(&vec![&env, m1.clone(), m2.clone()], &2);
+    let id = client.propose_action(&m1, &AdminAction::Pause, &(NOW + 10));
+    assert_eq!(client.try_approve_action(&m1, &id), Err(Ok(Error::AlreadyApproved)));
+    assert_eq!(client.get_action(&id).unwrap().approvals.len(), 1);
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::ThresholdNotMet)));
+}
+
+#[test]
+fn approved_action_cannot_run_after_expiry() {
+    let (env, client, _) = setup();
+    let (m1, m2) = (Address::generate(&env), Address::generate(&env));
+    client.set_council(&vec![&env, m1.clone(), m2.clone()], &2);
+    let id = client.propose_action(&m1, &AdminAction::Pause, &(NOW + 10));
+    client.approve_action(&m2, &id);
+    advance(&env, 11);
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::ProposalExpired)));
+    assert!(!client.pause_status().claims);
+}
+
+#[test]
+fn council_members_receive_the_admin_role() {
+    let (env, client, admin) = setup();
+    let (m1, m2) = (Address::generate(&env), Address::generate(&env));
+    client.set_council(&vec![&env, m1.clone(), m2.clone()], &2);
+    assert_eq!(client.get_council(), (vec![&env, m1.clone(), m2.clone()], 2));
+    assert!(client.has_role(&m1, &1));
+    assert!(client.has_role(&m2, &1));
+    assert!(client.has_role(&admin, &1));
+}
+
+#[test]
+fn dissolving_the_council_restores_the_sole_admin() {
+    let (env, client, admin) = setup();
+    let m = Address::generate(&env);
+    client.set_council(&vec![&env, m.clone()], &1);
+    let id = client.propose_action(&m, &AdminAction::DissolveCouncil, &(NOW + 10));
+    client.execute_action(&id);
+    assert_eq!(client.get_council(), (vec![&env], 0));
+    assert!(!client.has_role(&m, &1));
+    assert!(client.has_role(&admin, &1));
+    client.pause();
+    assert!(client.pause_status().claims);
+}
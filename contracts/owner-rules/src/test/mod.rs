@@ -0,0 +1,47 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, Val, Vec,
+};
+
+use crate::{OwnerRules, OwnerRulesClient};
+
+mod admin;
+mod claims;
+mod council;
+mod pause;
+mod recovery;
+
+const NOW: u64 = 1_000_000;
+const DAY: u64 = 86400;
+
+fn setup() -> (Env, OwnerRulesClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+    let admin = Address::generate(&env);
+    let id = env.register(OwnerRules, (admin.clone(),));
+    let client = OwnerRulesClient::new(&env, &id);
+    (env, client, admin)
+}
+
+fn mint_supervisor(client: &OwnerRulesClient, wallet: &Address) {
+    let token = client.generate_supervisor_claim_link(wallet, &None);
+    client.claim_nft(&token, wallet);
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|l| l.timestamp += secs);
+}
+
+/// Topics and data of the events the contract published during the last
+/// invocation.
+fn emitted(env: &Env, client: &OwnerRulesClient) -> std::vec::Vec<(Vec<Val>, Val)> {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(contract, _, _)| *contract == client.address)
+        .map(|(_, topics, data)| (topics, data))
+        .collect()
+}
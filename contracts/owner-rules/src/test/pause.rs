@@ -0,0 +1,88 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, IntoVal, Val, Vec};
+
+use super::{emitted, mint_supervisor, setup, NOW};
+use crate::contract::EVENT_VERSION;
+use crate::{AdminAction, Error, PauseStatus};
+
+fn only(claims: bool, issuance: bool, admin: bool) -> PauseStatus {
+    PauseStatus { claims, issuance, admin }
+}
+
+#[test]
+fn paused() {
+    let (env, client, _) = setup();
+    client.pause();
+    assert_eq!(
+        client.try_generate_supervisor_claim_link(&Address::generate(&env), &None),
+        Err(Ok(Error::Paused))
+    );
+}
+
+#[test]
+fn set_pause_emits_versioned_flags() {
+    let (env, client, _) = setup();
+    client.set_pause(&only(true, false, false));
+    let events = emitted(&env, &client);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("Paused"),).into_val(&env);
+    assert_eq!(topics, expected);
+    let data: (u32, PauseStatus) = data.into_val(&env);
+    assert_eq!(data, (EVENT_VERSION, only(true, false, false)));
+}
+
+#[test]
+fn claims_flag_blocks_redemption_only() {
+    let (env, client, _) = setup();
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&client, &supervisor);
+    client.set_pause(&only(true, false, false));
+    let wallet = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&wallet, &None);
+    assert_eq!(client.try_claim_nft(&token, &wallet), Err(Ok(Error::Paused)));
+    client.revoke_credential(&supervisor, &2);
+    client.propose_admin(&Address::generate(&env), &(NOW + 10));
+}
+
+#[test]
+fn issuance_flag_blocks_link_generation_and_revocation() {
+    let (env, client, _) = setup();
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&client, &supervisor);
+    let wallet = Address::generate(&env);
+    let token = client.generate_supervisor_claim_link(&wallet, &None);
+    client.set_pause(&only(false, true, false));
+    assert_eq!(
+        client.try_generate_supervisor_claim_link(&Address::generate(&env), &None),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(client.try_revoke_credential(&supervisor, &2), Err(Ok(Error::Paused)));
+    client.claim_nft(&token, &wallet);
+    client.propose_admin(&Address::generate(&env), &(NOW + 10));
+}
+
+#[test]
+fn issuance_flag_blocks_council_revocation() {
+    let (env, client, _) = setup();
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&client, &supervisor);
+    client.set_pause(&only(false, true, false));
+    let member = Address::generate(&env);
+    client.set_council(&vec![&env, member.clone()], &1);
+    let id = client.propose_action(&member, &AdminAction::RevokeCredential(supervisor.clone(), 2), &(NOW + 10));
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::Paused)));
+    assert!(client.has_role(&supervisor, &2));
+}
+
+#[test]
+fn admin_flag_blocks_handover_only() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin, &(NOW + 10));
+    client.set_pause(&only(false, false, true));
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::Paused)));
+    assert_eq!(client.try_propose_admin(&new_admin, &(NOW + 10)), Err(Ok(Error::Paused)));
+    client.cancel_admin_proposal();
+    mint_supervisor(&client, &Address::generate(&env));
+    assert_eq!(client.get_admin(), admin);
+}
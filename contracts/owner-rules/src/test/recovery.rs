@@ -0,0 +1,95 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use super::{advance, setup, DAY};
+use crate::Error;
+
+#[test]
+fn not_guardian() {
+    let (env, client, _) = setup();
+    assert_eq!(
+        client.try_propose_recovery(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(Error::NotGuardian))
+    );
+}
+
+#[test]
+fn invalid_threshold() {
+    let (env, client, _) = setup();
+    let guardians = vec![&env, Address::generate(&env)];
+    assert_eq!(client.try_set_guardians(&guardians, &2), Err(Ok(Error::InvalidThreshold)));
+}
+
+#[test]
+fn invalid_guardians() {
+    let (env, client, _) = setup();
+    let g = Address::generate(&env);
+    assert_eq!(client.try_set_guardians(&vec![&env, g.clone(), g], &1), Err(Ok(Error::InvalidGuardians)));
+}
+
+#[test]
+fn recovery_pending() {
+    let (env, client, _) = setup();
+    let (g1, g2) = (Address::generate(&env), Address::generate(&env));
+    client.set_guardians(&vec![&env, g1.clone(), g2.clone()], &2);
+    client.propose_recovery(&g1, &Address::generate(&env));
+    assert_eq!(client.try_propose_recovery(&g2, &Address::generate(&env)), Err(Ok(Error::RecoveryPending)));
+}
+
+#[test]
+fn no_recovery_pending() {
+    let (_, client, _) = setup();
+    assert_eq!(client.try_execute_recovery(), Err(Ok(Error::NoRecoveryPending)));
+}
+
+#[test]
+fn already_approved() {
+    let (env, client, _) = setup();
+    let (g1, g2) = (Address::generate(&env), Address::generate(&env));
+    client.set_guardians(&vec![&env, g1.clone(), g2], &2);
+    client.propose_recovery(&g1, &Address::generate(&env));
+    assert_eq!(client.try_approve_recovery(&g1), Err(Ok(Error::AlreadyApproved)));
+}
+
+#[test]
+fn approval_window_closed() {
+    let (env, client, _) = setup();
+    let (g1, g2) = (Address::generate(&env), Address::generate(&env));
+    client.set_guardians(&vec![&env, g1.clone(), g2.clone()], &2);
+    client.propose_recovery(&g1, &Address::generate(&env));
+    advance(&env, 3 * DAY + 1);
+    assert_eq!(client.try_approve_recovery(&g2), Err(Ok(Error::ApprovalWindowClosed)));
+}
+
+#[test]
+fn timelock_active() {
+    let (env, client, _) = setup();
+    let g = Address::generate(&env);
+    client.set_guardians(&vec![&env, g.clone()], &1);
+    client.propose_recovery(&g, &Address::generate(&env));
+    assert_eq!(client.try_execute_recovery(), Err(Ok(Error::TimelockActive)));
+}
+
+#[test]
+fn guardians_recover_the_admin_after_the_timelock() {
+    let (env, client, _) = setup();
+    let (g1, g2, new_admin) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    client.set_guardians(&vec![&env, g1.clone(), g2.clone()], &2);
+    client.propose_recovery(&g1, &new_admin);
+    client.approve_recovery(&g2);
+    advance(&env, 2 * DAY);
+    client.execute_recovery();
+    assert_eq!(client.get_admin(), new_admin);
+    assert!(client.get_recovery_proposal().is_none());
+}
+
+#[test]
+fn admin_veto_discards_an_approved_recovery() {
+    let (env, client, admin) = setup();
+    let g = Address::generate(&env);
+    client.set_guardians(&vec![&env, g.clone()], &1);
+    client.propose_recovery(&g, &Address::generate(&env));
+    client.veto_recovery();
+    advance(&env, 2 * DAY);
+    assert_eq!(client.try_execute_recovery(), Err(Ok(Error::NoRecoveryPending)));
+    assert_eq!(client.get_admin(), admin);
+}
@@ -1,15 +1,146 @@
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+#![allow(deprecated)]
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, Bytes,
+    BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
 
 const ADMIN_KEY: &Symbol = &symbol_short!("ADMIN");
 const NEXT_EVENT_ID_KEY: &Symbol = &symbol_short!("NEXT_EVT");
+const OWNER_RULES_KEY: &Symbol = &symbol_short!("OWN_RULES");
+const NEXT_CERT_ID_KEY: &Symbol = &symbol_short!("NEXT_CERT");
+const CERT_BASE_URI_KEY: &Symbol = &symbol_short!("CERT_URI");
+const NEXT_SERIES_ID_KEY: &Symbol = &symbol_short!("NEXT_SER");
+const LAST_DAY_KEY: &Symbol = &symbol_short!("LAST_DAY");
+const EARLY_CHECKIN_KEY: &Symbol = &symbol_short!("EARLY_CI");
+const LATE_GRACE_KEY: &Symbol = &symbol_short!("LATE_GRC");
+const PENDING_ADMIN_KEY: &Symbol = &symbol_short!("PEND_ADM");
+const PAUSE_FLAGS_KEY: &Symbol = &symbol_short!("PAUSE_FLG");
+const NEXT_SESSION_KEY: &Symbol = &symbol_short!("NEXT_SESS");
+
+const DEFAULT_EARLY_CHECKIN_SECS: u64 = 7200;
+const DEFAULT_LATE_GRACE_SECS: u64 = 0;
+
+const MAX_URI_LEN: u32 = 200;
+const MAX_SERIES_SESSIONS: u32 = 100;
+/// Upper bound on entries a filtered listing examines per call.
+const MAX_PAGE_SCAN: u32 = 200;
+/// Rotating-code windows a check-in session lasts; bounds the hashing
+/// `check_in` does to verify a code.
+const MAX_CODE_WINDOWS: u64 = 720;
+
+/// Events are indexed by the day (UTC) of their `start_ts`.
+const DAY_SECS: u64 = 86400;
+/// Bounds how far back `list_ongoing` looks in the day index; events open
+/// longer than this (late grace included) are kept in `DataKey::LongEvents`
+/// instead.
+const MAX_EVENT_DURATION_SECS: u64 = 30 * DAY_SECS;
+/// Marks a `list_ongoing` cursor pointing into `DataKey::LongEvents`.
+const LONG_EVENTS_CURSOR: u64 = 1 << 63;
+
+/// Schema version carried as the first data field of every contract event.
+pub(crate) const EVENT_VERSION: u32 = 1;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 7 * DAY_IN_LEDGERS;
+
+const ROLE_SUPERVISOR: u32 = 2;
+
+/// Subset of the `owner-rules` contract interface used to check credentials.
+#[contractclient(name = "OwnerRulesClient")]
+pub trait OwnerRulesInterface {
+    fn has_role(env: Env, wallet: Address, role_id: u32) -> bool;
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AdminNotSet = 1,
+    NotSupervisor = 2,
+    InvalidWindow = 3,
+    EventNotFound = 4,
+    TooEarly = 5,
+    OutsideWindow = 6,
+    AlreadyRegistered = 7,
+    AttendeeNotRegistered = 8,
+    OwnerRulesNotSet = 9,
+    NoCheckInSession = 10,
+    InvalidProof = 11,
+    VoucherKeyNotSet = 12,
+    VoucherAlreadyUsed = 13,
+    InvalidVoucher = 14,
+    EventNotClosed = 15,
+    CertificateAlreadyClaimed = 16,
+    CertificateNotFound = 17,
+    UriTooLong = 18,
+    EventCancelled = 19,
+    EventClosed = 20,
+    NotEventOrganizer = 21,
+    AlreadyOrganizer = 22,
+    OrganizerNotFound = 23,
+    AlreadyCheckedOut = 24,
+    BelowMinimumDuration = 25,
+    SeriesNotFound = 26,
+    InvalidRecurrence = 27,
+    NotEnrolled = 28,
+    CapacityReached = 29,
+    AlreadyEnrolled = 30,
+    InviteOnly = 31,
+    NoPendingAdmin = 32,
+    AdminProposalExpired = 33,
+    Paused = 34,
+    ProofAlreadyUsed = 35,
+    InvalidExpiry = 36,
+    CheckInSessionExpired = 37,
+}
 
 #[contracttype]
 pub enum DataKey {
     EventById(u64),
-    Supervisor(Address),
     Presence(u64, Address),
     AttendeesCount(u64),
     AttendeeByIndex(u64, u64),
+    AttendeeIndex(u64, Address),
+    CheckInSession(u64),
+    VoucherKey(Address),
+    VoucherNonce(Address, u64),
+    CertificateById(u64),
+    CertificateFor(u64, Address),
+    CertificatesCount(Address),
+    CertificateByOwnerIndex(Address, u64),
+    CreatorEventsCount(Address),
+    CreatorEventByIndex(Address, u64),
+    SeriesById(u64),
+    ActiveAttendeesCount(u64),
+    Enrollment(u64, Address),
+    EnrolledCount(u64),
+    WaitlistHead(u64),
+    WaitlistTail(u64),
+    WaitlistByIndex(u64, u64),
+    /// Waitlist slot of a waitlisted attendee.
+    WaitlistPosition(u64, Address),
+    AttendeeEventsCount(Address),
+    AttendeeEventByIndex(Address, u64),
+    AttendeeEventPosition(Address, u64),
+    /// `(start_ts, event_id)` of the events starting on a day, sorted.
+    EventsByDay(u64),
+    /// Ids of events open longer than `MAX_EVENT_DURATION_SECS`.
+    LongEvents,
+    /// Nonce of the last session a signed check-in of the attendee used.
+    CheckInNonce(u64, Address),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum EventStatus {
+    Scheduled = 0,
+    Open = 1,
+    Closed = 2,
+    Cancelled = 3,
 }
 
 #[contracttype]
@@ -18,6 +149,25 @@ pub struct EventData {
     pub name: String,
     pub start_ts: u64,
     pub end_ts: u64,
+    /// Registration opens this many seconds before `start_ts`.
+    pub early_checkin_secs: u64,
+    /// Registration stays open this many seconds after `end_ts`.
+    pub late_grace_secs: u64,
+    /// Attendance shorter than this does not count as present (0 disables).
+    pub min_duration_secs: u64,
+    /// Only enrolled attendees may be registered.
+    pub invite_only: bool,
+    /// Maximum enrolled and registered attendees (0 = unlimited).
+    pub max_capacity: u32,
+    pub creator: Address,
+    /// Series this event is a session of, if any.
+    pub series_id: Option<u64>,
+    /// Co-organizers allowed to manage attendance alongside the creator.
+    pub organizers: Vec<Address>,
+    /// Stored as `Scheduled` or `Cancelled`; `Open` and `Closed` are derived
+    /// from the ledger time whenever the event is read. An event is `Closed`
+    /// once its late grace period has elapsed.
+    pub status: EventStatus,
 }
 
 #[contracttype]
@@ -27,6 +177,42 @@ pub struct EventSummary {
     pub name: String,
     pub start_ts: u64,
     pub end_ts: u64,
+    pub status: EventStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum EnrollmentStatus {
+    Enrolled = 0,
+    Waitlisted = 1,
+}
+
+/// A recurring course or event made of individual session events.
+#[contracttype]
+#[derive(Clone)]
+pub struct SeriesData {
+    pub name: String,
+    pub creator: Address,
+    pub sessions: Vec<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SessionWindow {
+    pub start_ts: u64,
+    pub end_ts: u64,
+}
+
+/// Attendance of one attendee across a series, e.g. 24 of 30 sessions.
+#[contracttype]
+#[derive(Clone)]
+pub struct SeriesAttendance {
+    pub attended: u32,
+    /// Sessions already closed.
+    pub held: u32,
+    /// Sessions not cancelled.
+    pub total: u32,
 }
 
 #[contracttype]
@@ -35,6 +221,122 @@ pub struct AttendeeInfo {
     pub address: Address,
     pub registered_at: u64,
     pub active: bool,
+    pub checked_out_at: Option<u64>,
+    /// Time attended within `[start_ts, end_ts]`, set on check-out.
+    pub duration_secs: u64,
+}
+
+/// A page of a listing. Pass `next_cursor` back as `cursor` to continue;
+/// `None` means the listing is exhausted.
+#[contracttype]
+#[derive(Clone)]
+pub struct EventPage {
+    pub items: Vec<EventSummary>,
+    pub next_cursor: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttendeePage {
+    pub items: Vec<AttendeeInfo>,
+    pub next_cursor: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CertificatePage {
+    pub items: Vec<CertificateData>,
+    pub next_cursor: Option<u64>,
+}
+
+/// How a self check-in session authenticates attendees.
+#[contracttype]
+#[derive(Clone)]
+pub enum CheckInVerifier {
+    /// Rotating code: `(anchor, window_secs)` where `anchor` is
+    /// `sha256^n(seed)`. During the `k`-th `window_secs` period since the
+    /// session opened (counting from 1) the supervisor shows the 32-byte
+    /// code `sha256^(n-k)(seed)`, which admits every attendee in the room.
+    /// A code only reveals earlier ones; the previous window's code is
+    /// still accepted to absorb submission delay.
+    RotatingCode(BytesN<32>, u64),
+    /// ed25519 public key of the supervisor's check-in device.
+    SigningKey(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CheckInSession {
+    pub verifier: CheckInVerifier,
+    pub opened_by: Address,
+    pub opened_at: u64,
+    /// Unique per opened session; bound into `checkin_message`.
+    pub nonce: u64,
+}
+
+/// Offline attendance voucher signed by `supervisor`'s voucher key.
+#[contracttype]
+#[derive(Clone)]
+pub struct Voucher {
+    pub event_id: u64,
+    pub attendee: Address,
+    pub supervisor: Address,
+    pub issued_at: u64,
+    pub nonce: u64,
+}
+
+/// Non-transferable proof that `owner` attended `event_id`.
+#[contracttype]
+#[derive(Clone)]
+pub struct CertificateData {
+    pub id: u64,
+    pub event_id: u64,
+    pub owner: Address,
+    pub registered_at: u64,
+    pub duration_secs: u64,
+    pub issued_at: u64,
+}
+
+/// Emergency-stop flags. owner-rules has its own flags and storage; each
+/// contract is paused separately. Each flag halts a group of
+/// entrypoints; see `set_pause`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseStatus {
+    pub claims: bool,
+    pub issuance: bool,
+    pub registration: bool,
+    pub event_creation: bool,
+    pub admin: bool,
+}
+
+impl PauseStatus {
+    fn all(paused: bool) -> Self {
+        PauseStatus { claims: paused, issuance: paused, registration: paused, event_creation: paused, admin: paused }
+    }
+
+    fn any(&self) -> bool {
+        self.claims || self.issuance || self.registration || self.event_creation || self.admin
+    }
+
+    /// Flags set in `self` but not in `other`.
+    fn without(&self, other: &PauseStatus) -> Self {
+        PauseStatus {
+            claims: self.claims && !other.claims,
+            issuance: self.issuance && !other.issuance,
+            registration: self.registration && !other.registration,
+            event_creation: self.event_creation && !other.event_creation,
+            admin: self.admin && !other.admin,
+        }
+    }
+}
+
+/// Admin handover awaiting acceptance by `new_admin`.
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    pub expires_at: u64,
 }
 
 #[contract]
@@ -42,210 +344,935 @@ pub struct PresenceEvents;
 
 #[contractimpl]
 impl PresenceEvents {
-    pub fn __constructor(env: &Env, admin: Address) {
+    pub fn __constructor(env: &Env, admin: Address, owner_rules: Address) {
         env.storage().instance().set(ADMIN_KEY, &admin);
         env.storage().instance().set(NEXT_EVENT_ID_KEY, &1u64);
+        env.storage().instance().set(OWNER_RULES_KEY, &owner_rules);
     }
 
-    
+    pub fn set_owner_rules(env: &Env, contract: Address) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        env.storage().instance().set(OWNER_RULES_KEY, &contract);
+        Self::emit_owner_rules_updated(env, contract);
+        Ok(())
+    }
 
-    pub fn set_supervisor(env: &Env, user: Address, status: bool) {
-        Self::require_admin(env);
-        env.storage().instance().set(&DataKey::Supervisor(user), &status);
+    pub fn get_owner_rules(env: &Env) -> Option<Address> {
+        env.storage().instance().get(OWNER_RULES_KEY)
     }
 
-    pub fn create_event(env: &Env, name: String, start_ts: u64, end_ts: u64, operator: Address) -> u64 {
-        operator.require_auth();
-        let enabled: bool = env.storage().instance().get(&DataKey::Supervisor(operator.clone())).unwrap_or(false);
-        if !enabled { panic!("not supervisor"); }
-        if start_ts == 0 || end_ts == 0 || end_ts <= start_ts { panic!("invalid window"); }
+    pub fn create_event(env: &Env, name: String, start_ts: u64, end_ts: u64, operator: Address) -> Result<u64, Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        Self::require_supervisor(env, &operator)?;
+        Self::insert_event(env, name, start_ts, end_ts, operator, None)
+    }
+
+    /// Creates a series whose sessions are the given explicit windows.
+    pub fn create_series(env: &Env, name: String, operator: Address, sessions: Vec<SessionWindow>) -> Result<u64, Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        Self::require_supervisor(env, &operator)?;
+        if sessions.is_empty() || sessions.len() > MAX_SERIES_SESSIONS { return Err(Error::InvalidRecurrence); }
+        let series_id = Self::next_series_id(env);
+        let mut ids: Vec<u64> = Vec::new(env);
+        for w in sessions.iter() {
+            ids.push_back(Self::insert_event(env, name.clone(), w.start_ts, w.end_ts, operator.clone(), Some(series_id))?);
+        }
+        Self::store_series(env, series_id, SeriesData { name, creator: operator, sessions: ids });
+        Ok(series_id)
+    }
+
+    /// Creates a series of `count` sessions lasting `duration_secs`, the
+    /// first starting at `first_start_ts` and each following one
+    /// `interval_secs` later (e.g. 604800 for weekly classes).
+    pub fn create_recurring_series(
+        env: &Env,
+        name: String,
+        operator: Address,
+        first_start_ts: u64,
+        duration_secs: u64,
+        interval_secs: u64,
+        count: u32,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        Self::require_supervisor(env, &operator)?;
+        if count == 0 || count > MAX_SERIES_SESSIONS || duration_secs == 0 || interval_secs < duration_secs {
+            return Err(Error::InvalidRecurrence);
+        }
+        let series_id = Self::next_series_id(env);
+        let mut ids: Vec<u64> = Vec::new(env);
+        for k in 0..count as u64 {
+            let offset = interval_secs.checked_mul(k).ok_or(Error::InvalidRecurrence)?;
+            let start_ts = first_start_ts.checked_add(offset).ok_or(Error::InvalidRecurrence)?;
+            let end_ts = start_ts.checked_add(duration_secs).ok_or(Error::InvalidRecurrence)?;
+            ids.push_back(Self::insert_event(env, name.clone(), start_ts, end_ts, operator.clone(), Some(series_id))?);
+        }
+        Self::store_series(env, series_id, SeriesData { name, creator: operator, sessions: ids });
+        Ok(series_id)
+    }
+
+    pub fn get_series(env: &Env, series_id: u64) -> Option<SeriesData> {
+        Self::read_persistent(env, &DataKey::SeriesById(series_id))
+    }
+
+    pub fn list_series_sessions(env: &Env, series_id: u64) -> Result<Vec<EventSummary>, Error> {
+        let series: SeriesData = Self::read_persistent(env, &DataKey::SeriesById(series_id)).ok_or(Error::SeriesNotFound)?;
+        let now = env.ledger().timestamp();
+        let mut out: Vec<EventSummary> = Vec::new(env);
+        for id in series.sessions.iter() {
+            if let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id)) {
+                out.push_back(Self::summary(id, ev, now));
+            }
+        }
+        Ok(out)
+    }
+
+    /// Sessions of the series `attendee` counts as present at, against the
+    /// sessions held so far and the total planned.
+    pub fn get_series_attendance(env: &Env, series_id: u64, attendee: Address) -> Result<SeriesAttendance, Error> {
+        let series: SeriesData = Self::read_persistent(env, &DataKey::SeriesById(series_id)).ok_or(Error::SeriesNotFound)?;
+        let now = env.ledger().timestamp();
+        let mut out = SeriesAttendance { attended: 0, held: 0, total: 0 };
+        for id in series.sessions.iter() {
+            let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id)) else { continue; };
+            match Self::status_at(&ev, now) {
+                EventStatus::Cancelled => continue,
+                EventStatus::Closed => out.held += 1,
+                _ => {}
+            }
+            out.total += 1;
+            if Self::is_present(env, id, attendee.clone()) { out.attended += 1; }
+        }
+        Ok(out)
+    }
+
+    fn insert_event(env: &Env, name: String, start_ts: u64, end_ts: u64, operator: Address, series_id: Option<u64>) -> Result<u64, Error> {
+        Self::validate_window(start_ts, end_ts)?;
         let id = Self::next_event_id(env);
-        env.storage().instance().set(&DataKey::EventById(id), &EventData { name, start_ts, end_ts });
-        id
+        let (early_checkin_secs, late_grace_secs) = Self::get_default_checkin_window(env);
+        let ev = EventData {
+            name,
+            start_ts,
+            end_ts,
+            early_checkin_secs,
+            late_grace_secs,
+            min_duration_secs: 0,
+            invite_only: false,
+            max_capacity: 0,
+            creator: operator.clone(),
+            series_id,
+            organizers: Vec::new(env),
+            status: EventStatus::Scheduled,
+        };
+        Self::write_persistent(env, &DataKey::EventById(id), &ev);
+        let created: u64 = Self::read_persistent(env, &DataKey::CreatorEventsCount(operator.clone())).unwrap_or(0);
+        Self::write_persistent(env, &DataKey::CreatorEventByIndex(operator.clone(), created), &id);
+        Self::write_persistent(env, &DataKey::CreatorEventsCount(operator), &(created + 1));
+        Self::index_event_day(env, id, start_ts, Self::open_until(&ev));
+        Self::emit_event_created(env, id, &ev);
+        Ok(id)
     }
 
     pub fn get_event(env: &Env, event_id: u64) -> Option<EventData> {
-        env.storage().instance().get(&DataKey::EventById(event_id))
+        let mut ev: EventData = Self::read_persistent(env, &DataKey::EventById(event_id))?;
+        ev.status = Self::status_at(&ev, env.ledger().timestamp());
+        Some(ev)
     }
 
-    pub fn register_presence(env: &Env, event_id: u64, operator: Address, attendee: Address) {
-        operator.require_auth();
-        let enabled: bool = env.storage().instance().get(&DataKey::Supervisor(operator.clone())).unwrap_or(false);
-        if !enabled { panic!("not supervisor"); }
-        let ev: EventData = env.storage().instance().get(&DataKey::EventById(event_id)).expect("event not found");
+    pub fn update_event(env: &Env, event_id: u64, operator: Address, name: String) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        ev.name = name.clone();
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::emit_event_updated(env, event_id, operator, name);
+        Ok(())
+    }
+
+    pub fn reschedule_event(env: &Env, event_id: u64, operator: Address, start_ts: u64, end_ts: u64) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        match Self::status_at(&ev, env.ledger().timestamp()) {
+            EventStatus::Cancelled => return Err(Error::EventCancelled),
+            EventStatus::Closed => return Err(Error::EventClosed),
+            _ => {}
+        }
+        Self::validate_window(start_ts, end_ts)?;
+        Self::unindex_event_day(env, event_id, ev.start_ts, Self::open_until(&ev));
+        ev.start_ts = start_ts;
+        ev.end_ts = end_ts;
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::index_event_day(env, event_id, start_ts, Self::open_until(&ev));
+        Self::emit_event_rescheduled(env, event_id, operator, start_ts, end_ts);
+        Ok(())
+    }
+
+    /// Overrides how early attendees may register and how long after the
+    /// end registration stays open for this event.
+    pub fn set_checkin_window(env: &Env, event_id: u64, operator: Address, early_checkin_secs: u64, late_grace_secs: u64) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        Self::unindex_event_day(env, event_id, ev.start_ts, Self::open_until(&ev));
+        ev.early_checkin_secs = early_checkin_secs;
+        ev.late_grace_secs = late_grace_secs;
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::index_event_day(env, event_id, ev.start_ts, Self::open_until(&ev));
+        Self::emit_checkin_window_updated(env, event_id, operator, early_checkin_secs, late_grace_secs);
+        Ok(())
+    }
+
+    /// Sets the minimum checked-in time an attendee needs to count as present.
+    pub fn set_min_duration(env: &Env, event_id: u64, operator: Address, min_duration_secs: u64) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        ev.min_duration_secs = min_duration_secs;
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::emit_min_duration_updated(env, event_id, operator, min_duration_secs);
+        Ok(())
+    }
+
+    /// Sets whether the event only admits enrolled attendees and its
+    /// capacity (0 = unlimited). Seats freed by a larger capacity go to the
+    /// waitlist in order, up to one bounded pass; `promote_waitlist` fills
+    /// the rest.
+    pub fn set_enrollment_policy(env: &Env, event_id: u64, operator: Address, invite_only: bool, max_capacity: u32) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        ev.invite_only = invite_only;
+        ev.max_capacity = max_capacity;
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::emit_enrollment_policy_updated(env, event_id, operator, invite_only, max_capacity);
+        Self::fill_from_waitlist(env, event_id, &ev);
+        Ok(())
+    }
+
+    /// Promotes waitlisted attendees in order while the event has free seats,
+    /// examining at most `MAX_PAGE_SCAN` waitlist slots. Anyone may call it
+    /// to continue a promotion a bounded pass left unfinished. Returns the
+    /// number of attendees promoted.
+    pub fn promote_waitlist(env: &Env, event_id: u64) -> Result<u32, Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        Ok(Self::fill_from_waitlist(env, event_id, &ev))
+    }
+
+    /// Adds attendees to the enrollment list. Once capacity is reached the
+    /// remaining ones are waitlisted. Already enrolled attendees are skipped.
+    pub fn enroll_batch(env: &Env, event_id: u64, operator: Address, attendees: Vec<Address>) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        for addr in attendees.iter() {
+            if !Self::has_persistent(env, &DataKey::Enrollment(event_id, addr.clone())) {
+                Self::enroll(env, event_id, &ev, &addr);
+            }
+        }
+        Ok(())
+    }
+
+    /// Self-enrollment on events that are not invite-only.
+    pub fn self_enroll(env: &Env, event_id: u64, attendee: Address) -> Result<EnrollmentStatus, Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        attendee.require_auth();
+        let ev = Self::load_event(env, event_id)?;
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        if ev.invite_only { return Err(Error::InviteOnly); }
+        if Self::has_persistent(env, &DataKey::Enrollment(event_id, attendee.clone())) { return Err(Error::AlreadyEnrolled); }
+        Ok(Self::enroll(env, event_id, &ev, &attendee))
+    }
+
+    /// Withdraws an enrollment or waitlist entry. A freed seat goes to the
+    /// first attendee still on the waitlist, unless a lowered capacity is
+    /// still exceeded.
+    pub fn withdraw_enrollment(env: &Env, event_id: u64, attendee: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        attendee.require_auth();
+        let ev = Self::load_event(env, event_id)?;
+        let status: EnrollmentStatus = Self::read_persistent(env, &DataKey::Enrollment(event_id, attendee.clone()))
+            .ok_or(Error::NotEnrolled)?;
+        Self::remove_persistent(env, &DataKey::Enrollment(event_id, attendee.clone()));
+        Self::emit_enrollment_withdrawn(env, event_id, attendee.clone());
+        if status == EnrollmentStatus::Enrolled {
+            let enrolled: u32 = Self::read_persistent(env, &DataKey::EnrolledCount(event_id)).unwrap_or(0);
+            Self::write_persistent(env, &DataKey::EnrolledCount(event_id), &enrolled.saturating_sub(1));
+            Self::fill_from_waitlist(env, event_id, &ev);
+        } else {
+            let position_key = DataKey::WaitlistPosition(event_id, attendee.clone());
+            if let Some(slot) = Self::read_persistent::<u64>(env, &position_key) {
+                Self::remove_persistent(env, &DataKey::WaitlistByIndex(event_id, slot));
+                Self::remove_persistent(env, &position_key);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_enrollment(env: &Env, event_id: u64, attendee: Address) -> Option<EnrollmentStatus> {
+        Self::read_persistent(env, &DataKey::Enrollment(event_id, attendee))
+    }
+
+    pub fn enrolled_count(env: &Env, event_id: u64) -> u32 {
+        Self::read_persistent(env, &DataKey::EnrolledCount(event_id)).unwrap_or(0)
+    }
+
+    /// Window applied to events created from now on.
+    pub fn set_default_checkin_window(env: &Env, early_checkin_secs: u64, late_grace_secs: u64) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        env.storage().instance().set(EARLY_CHECKIN_KEY, &early_checkin_secs);
+        env.storage().instance().set(LATE_GRACE_KEY, &late_grace_secs);
+        Self::emit_default_checkin_window_updated(env, early_checkin_secs, late_grace_secs);
+        Ok(())
+    }
+
+    /// Returns `(early_checkin_secs, late_grace_secs)`.
+    pub fn get_default_checkin_window(env: &Env) -> (u64, u64) {
+        let early: u64 = env.storage().instance().get(EARLY_CHECKIN_KEY).unwrap_or(DEFAULT_EARLY_CHECKIN_SECS);
+        let late: u64 = env.storage().instance().get(LATE_GRACE_KEY).unwrap_or(DEFAULT_LATE_GRACE_SECS);
+        (early, late)
+    }
+
+    pub fn add_organizer(env: &Env, event_id: u64, operator: Address, organizer: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        if organizer == ev.creator || ev.organizers.contains(&organizer) { return Err(Error::AlreadyOrganizer); }
+        ev.organizers.push_back(organizer.clone());
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::emit_organizer_added(env, event_id, organizer, operator);
+        Ok(())
+    }
+
+    pub fn remove_organizer(env: &Env, event_id: u64, operator: Address, organizer: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        let idx = ev.organizers.first_index_of(&organizer).ok_or(Error::OrganizerNotFound)?;
+        ev.organizers.remove(idx);
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::emit_organizer_removed(env, event_id, organizer, operator);
+        Ok(())
+    }
+
+    pub fn cancel_event(env: &Env, event_id: u64, operator: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.event_creation)?;
+        let mut ev = Self::load_event(env, event_id)?;
+        Self::require_event_manager(env, &ev, &operator)?;
+        match Self::status_at(&ev, env.ledger().timestamp()) {
+            EventStatus::Cancelled => return Err(Error::EventCancelled),
+            EventStatus::Closed => return Err(Error::EventClosed),
+            _ => {}
+        }
+        ev.status = EventStatus::Cancelled;
+        Self::write_persistent(env, &DataKey::EventById(event_id), &ev);
+        Self::emit_event_cancelled(env, event_id, operator);
+        Ok(())
+    }
+
+    /// Extends the TTL of an event record, its attendee counters and check-in
+    /// session. Attendee rows are bumped separately via `bump_attendees`.
+    pub fn bump_event(env: &Env, event_id: u64) -> Result<(), Error> {
+        Self::load_event(env, event_id)?;
+        Self::read_persistent::<u64>(env, &DataKey::AttendeesCount(event_id));
+        Self::read_persistent::<u64>(env, &DataKey::ActiveAttendeesCount(event_id));
+        Self::read_persistent::<CheckInSession>(env, &DataKey::CheckInSession(event_id));
+        Self::bump_instance(env);
+        Ok(())
+    }
+
+    /// Extends the TTL of up to `limit` attendee rows of an event, starting
+    /// at index `cursor`, together with their presence flags.
+    pub fn bump_attendees(env: &Env, event_id: u64, cursor: u64, limit: u32) -> Result<(), Error> {
+        Self::load_event(env, event_id)?;
+        let count: u64 = Self::read_persistent(env, &DataKey::AttendeesCount(event_id)).unwrap_or(0);
+        let end = cursor.saturating_add(limit as u64).min(count);
+        for i in cursor..end {
+            let info: Option<AttendeeInfo> = Self::read_persistent(env, &DataKey::AttendeeByIndex(event_id, i));
+            if let Some(info) = info {
+                Self::read_persistent::<bool>(env, &DataKey::Presence(event_id, info.address.clone()));
+                Self::read_persistent::<u64>(env, &DataKey::AttendeeIndex(event_id, info.address));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn register_presence(env: &Env, event_id: u64, operator: Address, attendee: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
         let now = env.ledger().timestamp();
-        let early_from = ev.start_ts.saturating_sub(7200);
-        if now < early_from { panic!("too early"); }
-        if now > ev.end_ts { panic!("outside window"); }
-        let already: bool = env.storage().instance().get(&DataKey::Presence(event_id, attendee.clone())).unwrap_or(false);
-        if already { panic!("already registered"); }
-        env.storage().instance().set(&DataKey::Presence(event_id, attendee.clone()), &true);
-        let count: u64 = env.storage().instance().get(&DataKey::AttendeesCount(event_id)).unwrap_or(0);
-        env.storage().instance().set(&DataKey::AttendeeByIndex(event_id, count), &AttendeeInfo {
-            address: attendee.clone(),
-            registered_at: now,
-            active: true
-        });
-        env.storage().instance().set(&DataKey::AttendeesCount(event_id), &(count + 1));
+        Self::check_window(&ev, now)?;
+        if !Self::record_presence(env, event_id, &ev, &attendee, now, &operator)? { return Err(Error::AlreadyRegistered); }
+        Ok(())
     }
 
-    pub fn register_presence_batch(env: &Env, event_id: u64, operator: Address, attendees: Vec<Address>) {
-        operator.require_auth();
-        let enabled: bool = env.storage().instance().get(&DataKey::Supervisor(operator.clone())).unwrap_or(false);
-        if !enabled { panic!("not supervisor"); }
-        let ev: EventData = env.storage().instance().get(&DataKey::EventById(event_id)).expect("event not found");
+    /// Registers several attendees at once. Already registered attendees are
+    /// skipped; attendees the enrollment policy does not admit (not enrolled
+    /// or over capacity) are skipped too and returned.
+    pub fn register_presence_batch(env: &Env, event_id: u64, operator: Address, attendees: Vec<Address>) -> Result<Vec<Address>, Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
         let now = env.ledger().timestamp();
-        let early_from = ev.start_ts.saturating_sub(7200);
-        if now < early_from { panic!("too early"); }
-        if now > ev.end_ts { panic!("outside window"); }
-        let mut count: u64 = env.storage().instance().get(&DataKey::AttendeesCount(event_id)).unwrap_or(0);
-        let mut i = 0u32;
-        while i < attendees.len() {
-            let addr = attendees.get(i).unwrap();
-            let already: bool = env.storage().instance().get(&DataKey::Presence(event_id, addr.clone())).unwrap_or(false);
-            if !already {
-                env.storage().instance().set(&DataKey::Presence(event_id, addr.clone()), &true);
-                env.storage().instance().set(&DataKey::AttendeeByIndex(event_id, count), &AttendeeInfo {
-                    address: addr.clone(),
-                    registered_at: now,
-                    active: true
-                });
-                count += 1;
+        Self::check_window(&ev, now)?;
+        let mut rejected = Vec::new(env);
+        for addr in attendees.iter() {
+            if Self::record_presence(env, event_id, &ev, &addr, now, &operator).is_err() {
+                rejected.push_back(addr);
             }
-            i += 1;
         }
-        env.storage().instance().set(&DataKey::AttendeesCount(event_id), &count);
+        Ok(rejected)
+    }
+
+    pub fn register_checkout(env: &Env, event_id: u64, operator: Address, attendee: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
+        let now = env.ledger().timestamp();
+        Self::check_window(&ev, now)?;
+        Self::record_checkout(env, event_id, &ev, &attendee, now, &operator)
+    }
+
+    /// Checks out every listed attendee that is registered and not yet
+    /// checked out; the others are skipped.
+    pub fn register_checkout_batch(env: &Env, event_id: u64, operator: Address, attendees: Vec<Address>) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
+        let now = env.ledger().timestamp();
+        Self::check_window(&ev, now)?;
+        for addr in attendees.iter() {
+            let _ = Self::record_checkout(env, event_id, &ev, &addr, now, &operator);
+        }
+        Ok(())
+    }
+
+    /// Attendance duration in seconds, or `None` if the attendee has not
+    /// checked out.
+    pub fn get_attendance_duration(env: &Env, event_id: u64, attendee: Address) -> Option<u64> {
+        let (_, info) = Self::find_attendee(env, event_id, &attendee)?;
+        if !info.active { return None; }
+        info.checked_out_at.map(|_| info.duration_secs)
+    }
+
+    /// Whether the attendee is registered and meets the event's minimum
+    /// duration.
+    pub fn is_present(env: &Env, event_id: u64, attendee: Address) -> bool {
+        let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(event_id)) else { return false; };
+        match Self::find_attendee(env, event_id, &attendee) {
+            Some((_, info)) => info.active && Self::meets_min_duration(&ev, &info),
+            None => false,
+        }
+    }
+
+    /// Opens (or rotates) the self check-in session of an event. Attendees
+    /// then call `check_in` with a proof accepted by `verifier`.
+    pub fn open_checkin_session(env: &Env, event_id: u64, operator: Address, verifier: CheckInVerifier) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
+        if let CheckInVerifier::RotatingCode(_, 0) = verifier { return Err(Error::InvalidWindow); }
+        let nonce: u64 = env.storage().instance().get(NEXT_SESSION_KEY).unwrap_or(1u64);
+        env.storage().instance().set(NEXT_SESSION_KEY, &(nonce + 1));
+        let session = CheckInSession { verifier, opened_by: operator.clone(), opened_at: env.ledger().timestamp(), nonce };
+        Self::write_persistent(env, &DataKey::CheckInSession(event_id), &session);
+        Self::emit_checkin_opened(env, event_id, operator, session.verifier);
+        Ok(())
+    }
+
+    pub fn close_checkin_session(env: &Env, event_id: u64, operator: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
+        if !Self::has_persistent(env, &DataKey::CheckInSession(event_id)) { return Err(Error::NoCheckInSession); }
+        Self::remove_persistent(env, &DataKey::CheckInSession(event_id));
+        Self::emit_checkin_closed(env, event_id, operator);
+        Ok(())
+    }
+
+    pub fn get_checkin_session(env: &Env, event_id: u64) -> Option<CheckInSession> {
+        Self::read_persistent(env, &DataKey::CheckInSession(event_id))
+    }
+
+    /// Self check-in. With `RotatingCode` the proof is the 32-byte code of
+    /// the current (or previous) window; with `SigningKey` it is an ed25519
+    /// signature over `checkin_message(event_id, attendee, nonce)`, usable
+    /// once per session. A signature that does not verify aborts the
+    /// invocation in the host instead of returning `InvalidProof`.
+    pub fn check_in(env: &Env, event_id: u64, attendee: Address, proof: Bytes) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        attendee.require_auth();
+        let ev = Self::load_event(env, event_id)?;
+        let session: CheckInSession = Self::read_persistent(env, &DataKey::CheckInSession(event_id))
+            .ok_or(Error::NoCheckInSession)?;
+        let now = env.ledger().timestamp();
+        match session.verifier {
+            CheckInVerifier::RotatingCode(anchor, window_secs) => {
+                let window = now.saturating_sub(session.opened_at) / window_secs + 1;
+                if window > MAX_CODE_WINDOWS { return Err(Error::CheckInSessionExpired); }
+                let code = BytesN::<32>::try_from(proof).map_err(|_| Error::InvalidProof)?;
+                if !Self::is_window_code(env, code, &anchor, window) { return Err(Error::InvalidProof); }
+            }
+            CheckInVerifier::SigningKey(public_key) => {
+                let nonce_key = DataKey::CheckInNonce(event_id, attendee.clone());
+                if Self::read_persistent::<u64>(env, &nonce_key) == Some(session.nonce) { return Err(Error::ProofAlreadyUsed); }
+                let signature = BytesN::<64>::try_from(proof).map_err(|_| Error::InvalidProof)?;
+                let message = Self::checkin_message(env, event_id, attendee.clone(), session.nonce);
+                env.crypto().ed25519_verify(&public_key, &message, &signature);
+                Self::write_persistent(env, &nonce_key, &session.nonce);
+            }
+        }
+        Self::check_window(&ev, now)?;
+        if !Self::record_presence(env, event_id, &ev, &attendee, now, &attendee)? { return Err(Error::AlreadyRegistered); }
+        Ok(())
+    }
+
+    /// Message a session signing key must sign to admit `attendee`; `nonce`
+    /// is the one of the currently open session.
+    pub fn checkin_message(env: &Env, event_id: u64, attendee: Address, nonce: u64) -> Bytes {
+        let mut msg = env.current_contract_address().to_xdr(env);
+        msg.extend_from_slice(&event_id.to_be_bytes());
+        msg.extend_from_slice(&nonce.to_be_bytes());
+        msg.append(&attendee.to_xdr(env));
+        msg
+    }
+
+    /// Registers the ed25519 key `operator` signs offline vouchers with.
+    pub fn set_voucher_key(env: &Env, operator: Address, public_key: BytesN<32>) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        Self::require_supervisor(env, &operator)?;
+        Self::write_persistent(env, &DataKey::VoucherKey(operator.clone()), &public_key);
+        Self::emit_voucher_key_set(env, operator, public_key);
+        Ok(())
+    }
+
+    pub fn remove_voucher_key(env: &Env, operator: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.issuance)?;
+        operator.require_auth();
+        if !Self::has_persistent(env, &DataKey::VoucherKey(operator.clone())) { return Err(Error::VoucherKeyNotSet); }
+        Self::remove_persistent(env, &DataKey::VoucherKey(operator.clone()));
+        Self::emit_voucher_key_removed(env, operator);
+        Ok(())
+    }
+
+    pub fn get_voucher_key(env: &Env, supervisor: Address) -> Option<BytesN<32>> {
+        Self::read_persistent(env, &DataKey::VoucherKey(supervisor))
+    }
+
+    /// Redeems a supervisor-signed attendance voucher. Anyone may submit it;
+    /// the attendee is registered as of `voucher.issued_at`. A voucher
+    /// issued in the future is `InvalidVoucher`; a signature that does not
+    /// verify aborts the invocation in the host, so every typed check runs
+    /// before verification.
+    pub fn redeem_voucher(env: &Env, voucher: Voucher, signature: BytesN<64>) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, voucher.event_id)?;
+        let public_key: BytesN<32> = Self::read_persistent(env, &DataKey::VoucherKey(voucher.supervisor.clone()))
+            .ok_or(Error::VoucherKeyNotSet)?;
+        if !Self::is_supervisor(env, &voucher.supervisor)? { return Err(Error::NotSupervisor); }
+        if !Self::is_organizer(&ev, &voucher.supervisor) { return Err(Error::NotEventOrganizer); }
+        let nonce_key = DataKey::VoucherNonce(voucher.supervisor.clone(), voucher.nonce);
+        if Self::has_persistent(env, &nonce_key) { return Err(Error::VoucherAlreadyUsed); }
+        if voucher.issued_at > env.ledger().timestamp() { return Err(Error::InvalidVoucher); }
+        Self::check_window(&ev, voucher.issued_at)?;
+        let message = Self::voucher_message(env, voucher.event_id, voucher.attendee.clone(), voucher.issued_at, voucher.nonce);
+        env.crypto().ed25519_verify(&public_key, &message, &signature);
+        Self::write_persistent(env, &nonce_key, &true);
+        if !Self::record_presence(env, voucher.event_id, &ev, &voucher.attendee, voucher.issued_at, &voucher.supervisor)? {
+            return Err(Error::AlreadyRegistered);
+        }
+        Ok(())
+    }
+
+    /// Message a supervisor's voucher key must sign.
+    pub fn voucher_message(env: &Env, event_id: u64, attendee: Address, issued_at: u64, nonce: u64) -> Bytes {
+        let mut msg = env.current_contract_address().to_xdr(env);
+        msg.extend_from_slice(&event_id.to_be_bytes());
+        msg.append(&attendee.to_xdr(env));
+        msg.extend_from_slice(&issued_at.to_be_bytes());
+        msg.extend_from_slice(&nonce.to_be_bytes());
+        msg
     }
 
     pub fn has_presence(env: &Env, event_id: u64, attendee: Address) -> bool {
-        env.storage().instance().get(&DataKey::Presence(event_id, attendee)).unwrap_or(false)
+        Self::read_persistent(env, &DataKey::Presence(event_id, attendee)).unwrap_or(false)
     }
 
-    pub fn list_attendees(env: &Env, event_id: u64, cursor: u64, limit: u32) -> Vec<AttendeeInfo> {
-        let count: u64 = env.storage().instance().get(&DataKey::AttendeesCount(event_id)).unwrap_or(0);
-        let mut out: Vec<AttendeeInfo> = Vec::new(env);
-        if limit == 0 { return out; }
-        
-        let mut skipped = 0u64;
-        let mut taken = 0u32;
-        let mut i = 0u64;
-        
-        while i < count && taken < limit {
-            if let Some(attendee) = env.storage().instance().get(&DataKey::AttendeeByIndex(event_id, i)) {
-                if skipped < cursor {
-                    skipped += 1;
-                } else {
-                    out.push_back(attendee);
-                    taken += 1;
-                }
+    /// Attendees in registration order starting at index `cursor`. With
+    /// `filter_active` removed attendees are skipped.
+    pub fn list_attendees(env: &Env, event_id: u64, cursor: u64, limit: u32, filter_active: bool) -> AttendeePage {
+        let count: u64 = Self::read_persistent(env, &DataKey::AttendeesCount(event_id)).unwrap_or(0);
+        let mut items: Vec<AttendeeInfo> = Vec::new(env);
+        let mut i = cursor;
+        let mut scanned = 0u32;
+        while i < count && items.len() < limit && scanned < MAX_PAGE_SCAN {
+            let info: Option<AttendeeInfo> = Self::read_persistent(env, &DataKey::AttendeeByIndex(event_id, i));
+            if let Some(info) = info && (info.active || !filter_active) {
+                items.push_back(info);
             }
             i += 1;
+            scanned += 1;
         }
-        out
+        AttendeePage { items, next_cursor: if i < count { Some(i) } else { None } }
     }
 
-    pub fn remove_presence(env: &Env, event_id: u64, operator: Address, attendee: Address) {
-        operator.require_auth();
-        let is_supervisor: bool = env.storage().instance().get(&DataKey::Supervisor(operator.clone())).unwrap_or(false);
-        if !is_supervisor { panic!("not supervisor"); }
+    /// Number of registered attendees, excluding removed ones.
+    pub fn active_attendees_count(env: &Env, event_id: u64) -> u64 {
+        Self::read_persistent(env, &DataKey::ActiveAttendeesCount(event_id)).unwrap_or(0)
+    }
+
+    pub fn remove_presence(env: &Env, event_id: u64, operator: Address, attendee: Address) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.registration)?;
+        let ev = Self::load_event(env, event_id)?;
+        Self::require_organizer(env, &ev, &operator)?;
         
         // Verificar se a presença existe
-        let has_presence: bool = env.storage().instance().get(&DataKey::Presence(event_id, attendee.clone())).unwrap_or(false);
-        if !has_presence { panic!("attendee not registered"); }
+        let has_presence: bool = Self::read_persistent(env, &DataKey::Presence(event_id, attendee.clone())).unwrap_or(false);
+        if !has_presence { return Err(Error::AttendeeNotRegistered); }
         
         // Marcar presença como inativa (não removemos do storage, apenas marcamos como inativa)
-        let count: u64 = env.storage().instance().get(&DataKey::AttendeesCount(event_id)).unwrap_or(0);
-        let mut found = false;
-        let mut i = 0u64;
+        if let Some((i, mut info)) = Self::find_attendee(env, event_id, &attendee) {
+            info.active = false;
+            Self::write_persistent(env, &DataKey::AttendeeByIndex(event_id, i), &info);
+        }
         
-        while i < count && !found {
-            let attendee_info: Option<AttendeeInfo> = env.storage().instance().get(&DataKey::AttendeeByIndex(event_id, i));
-            if let Some(mut info) = attendee_info {
-                if info.address == attendee {
-                    info.active = false;
-                    env.storage().instance().set(&DataKey::AttendeeByIndex(event_id, i), &info);
-                    found = true;
-                }
+        // Remover a presença do mapping principal
+        Self::write_persistent(env, &DataKey::Presence(event_id, attendee.clone()), &false);
+        let active: u64 = Self::read_persistent(env, &DataKey::ActiveAttendeesCount(event_id)).unwrap_or(0);
+        Self::write_persistent(env, &DataKey::ActiveAttendeesCount(event_id), &active.saturating_sub(1));
+        Self::unindex_attendee_event(env, &attendee, event_id);
+        Self::emit_presence_removed(env, event_id, attendee, operator);
+        Ok(())
+    }
+
+    /// Events `attendee` is currently registered at. Removals move the last
+    /// entry into the freed slot, so the order is not chronological.
+    pub fn list_events_for_attendee(env: &Env, attendee: Address, cursor: u64, limit: u32) -> EventPage {
+        let mut items: Vec<EventSummary> = Vec::new(env);
+        let now = env.ledger().timestamp();
+        let count = Self::attendance_count(env, attendee.clone());
+        let mut i = cursor;
+        while i < count && items.len() < limit {
+            let id: Option<u64> = Self::read_persistent(env, &DataKey::AttendeeEventByIndex(attendee.clone(), i));
+            if let Some(id) = id && let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id)) {
+                items.push_back(Self::summary(id, ev, now));
             }
             i += 1;
         }
-        
-        // Remover a presença do mapping principal
-        env.storage().instance().set(&DataKey::Presence(event_id, attendee), &false);
+        EventPage { items, next_cursor: if i < count { Some(i) } else { None } }
     }
 
-    pub fn list_events(env: &Env, start_id: u64, limit: u32) -> Vec<EventSummary> {
-        let mut out: Vec<EventSummary> = Vec::new(env);
-        if limit == 0 { return out; }
-        let mut i = start_id;
-        let max = start_id.saturating_add(limit as u64);
-        while i < max {
-            let opt: Option<EventData> = env.storage().instance().get(&DataKey::EventById(i));
+    pub fn attendance_count(env: &Env, attendee: Address) -> u64 {
+        Self::read_persistent(env, &DataKey::AttendeeEventsCount(attendee)).unwrap_or(0)
+    }
+
+    /// Events by ascending id, starting at id `cursor`.
+    pub fn list_events(env: &Env, cursor: u64, limit: u32) -> EventPage {
+        let mut items: Vec<EventSummary> = Vec::new(env);
+        let now = env.ledger().timestamp();
+        let next_id: u64 = env.storage().instance().get(NEXT_EVENT_ID_KEY).unwrap_or(1u64);
+        let mut i = cursor.max(1);
+        while i < next_id && items.len() < limit {
+            let opt: Option<EventData> = Self::read_persistent(env, &DataKey::EventById(i));
             if let Some(ev) = opt {
-                out.push_back(EventSummary { id: i, name: ev.name, start_ts: ev.start_ts, end_ts: ev.end_ts });
+                items.push_back(Self::summary(i, ev, now));
             }
             i += 1;
         }
-        out
+        EventPage { items, next_cursor: if i < next_id { Some(i) } else { None } }
     }
 
-    pub fn list_upcoming(env: &Env) -> Vec<EventSummary> {
-        let mut selected: Vec<EventSummary> = Vec::new(env);
+    pub fn list_events_by_creator(env: &Env, creator: Address, cursor: u64, limit: u32) -> EventPage {
+        let mut items: Vec<EventSummary> = Vec::new(env);
         let now = env.ledger().timestamp();
-        let mut id: u64 = env.storage().instance().get(NEXT_EVENT_ID_KEY).unwrap_or(1u64);
-        if id == 0 { return selected; }
-        if id > 0 { id -= 1; }
-        let mut count = 0u32;
-        while id >= 1 && count < 64 {
-            let opt: Option<EventData> = env.storage().instance().get(&DataKey::EventById(id));
-            if let Some(ev) = opt {
-                if ev.end_ts > now {
-                    let item = EventSummary { id, name: ev.name, start_ts: ev.start_ts, end_ts: ev.end_ts };
-                    let mut inserted = false;
-                    let mut idx = 0u32;
-                    while idx < selected.len() {
-                        let cur = selected.get(idx).unwrap();
-                        if item.start_ts < cur.start_ts {
-                            selected.insert(idx, item.clone());
-                            inserted = true;
-                            break;
-                        }
-                        idx += 1;
-                    }
-                    if !inserted { selected.push_back(item); }
-                    if selected.len() > 5 { selected.pop_back(); }
-                }
+        let count: u64 = Self::read_persistent(env, &DataKey::CreatorEventsCount(creator.clone())).unwrap_or(0);
+        let mut i = cursor;
+        while i < count && items.len() < limit {
+            let id: Option<u64> = Self::read_persistent(env, &DataKey::CreatorEventByIndex(creator.clone(), i));
+            if let Some(id) = id && let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id)) {
+                items.push_back(Self::summary(id, ev, now));
+            }
+            i += 1;
+        }
+        EventPage { items, next_cursor: if i < count { Some(i) } else { None } }
+    }
+
+    /// Events not yet started, soonest first.
+    pub fn list_upcoming(env: &Env, cursor: u64, limit: u32) -> EventPage {
+        let now = env.ledger().timestamp();
+        let last_day: u64 = env.storage().instance().get(LAST_DAY_KEY).unwrap_or(0);
+        Self::scan_days(env, now / DAY_SECS, last_day, cursor, limit, |ev| {
+            ev.status != EventStatus::Cancelled && ev.start_ts > now
+        })
+    }
+
+    /// Events starting within `[from_ts, to_ts]`, by start time.
+    pub fn list_in_range(env: &Env, from_ts: u64, to_ts: u64, cursor: u64, limit: u32) -> EventPage {
+        if to_ts < from_ts { return EventPage { items: Vec::new(env), next_cursor: None }; }
+        let last_day: u64 = env.storage().instance().get(LAST_DAY_KEY).unwrap_or(0);
+        Self::scan_days(env, from_ts / DAY_SECS, (to_ts / DAY_SECS).min(last_day), cursor, limit, |ev| {
+            ev.start_ts >= from_ts && ev.start_ts <= to_ts
+        })
+    }
+
+    /// Open events (`start_ts <= now <= end_ts + late_grace_secs`), so
+    /// attendees can still register during the grace period: first those
+    /// from the recent day index, then long events.
+    pub fn list_ongoing(env: &Env, cursor: u64, limit: u32) -> EventPage {
+        let now = env.ledger().timestamp();
+        let ongoing = |ev: &EventData| Self::status_at(ev, now) == EventStatus::Open;
+        let mut page = if cursor & LONG_EVENTS_CURSOR == 0 {
+            let from_day = now.saturating_sub(MAX_EVENT_DURATION_SECS) / DAY_SECS;
+            Self::scan_days(env, from_day, now / DAY_SECS, cursor, limit, |ev| !Self::is_long(ev) && ongoing(ev))
+        } else {
+            EventPage { items: Vec::new(env), next_cursor: None }
+        };
+        if page.next_cursor.is_some() { return page; }
+        let long: Vec<u64> = Self::read_persistent(env, &DataKey::LongEvents).unwrap_or(Vec::new(env));
+        let mut i = if cursor & LONG_EVENTS_CURSOR == 0 { 0 } else { (cursor & !LONG_EVENTS_CURSOR) as u32 };
+        while i < long.len() && page.items.len() < limit {
+            let id = long.get_unchecked(i);
+            if let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id)) && ongoing(&ev) {
+                page.items.push_back(Self::summary(id, ev, now));
+            }
+            i += 1;
+        }
+        page.next_cursor = if i < long.len() { Some(LONG_EVENTS_CURSOR | i as u64) } else { None };
+        page
+    }
+
+    /// Closed events from the newest id down, starting at id `cursor`
+    /// (0 starts from the most recent event).
+    pub fn list_closed(env: &Env, cursor: u64, limit: u32) -> EventPage {
+        let mut items: Vec<EventSummary> = Vec::new(env);
+        let now = env.ledger().timestamp();
+        let next_id: u64 = env.storage().instance().get(NEXT_EVENT_ID_KEY).unwrap_or(1u64);
+        let mut id = if cursor == 0 || cursor >= next_id { next_id.saturating_sub(1) } else { cursor };
+        let mut scanned = 0u32;
+        while id >= 1 && items.len() < limit && scanned < MAX_PAGE_SCAN {
+            let opt: Option<EventData> = Self::read_persistent(env, &DataKey::EventById(id));
+            if let Some(ev) = opt && Self::status_at(&ev, now) == EventStatus::Closed {
+                items.push_back(Self::summary(id, ev, now));
             }
-            if id == 1 { break; }
             id -= 1;
-            count += 1;
+            scanned += 1;
         }
-        selected
+        EventPage { items, next_cursor: if id >= 1 { Some(id) } else { None } }
     }
 
-    pub fn list_closed(env: &Env, cursor: u64, limit: u32) -> Vec<EventSummary> {
-        let mut out: Vec<EventSummary> = Vec::new(env);
-        if limit == 0 { return out; }
+    pub fn set_certificate_base_uri(env: &Env, new_uri: String) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        if new_uri.len() > MAX_URI_LEN { return Err(Error::UriTooLong); }
+        env.storage().instance().set(CERT_BASE_URI_KEY, &new_uri);
+        Self::emit_certificate_base_uri_updated(env, new_uri);
+        Ok(())
+    }
+
+    /// Mints the soulbound attendance certificate of a closed event to one of
+    /// its active attendees. Certificates have no transfer entrypoint.
+    pub fn claim_certificate(env: &Env, event_id: u64, attendee: Address) -> Result<u64, Error> {
+        Self::require_not_paused(env, |p| p.claims)?;
+        attendee.require_auth();
+        let ev = Self::load_event(env, event_id)?;
         let now = env.ledger().timestamp();
-        let mut id: u64 = env.storage().instance().get(NEXT_EVENT_ID_KEY).unwrap_or(1u64);
-        if id == 0 { return out; }
-        if id > 0 { id -= 1; }
-        let mut skipped = 0u64;
-        let mut taken = 0u32;
-        while id >= 1 && taken < limit {
-            let opt: Option<EventData> = env.storage().instance().get(&DataKey::EventById(id));
-            if let Some(ev) = opt {
-                if ev.end_ts <= now {
-                    if skipped < cursor { skipped += 1; } else {
-                        out.push_back(EventSummary { id, name: ev.name, start_ts: ev.start_ts, end_ts: ev.end_ts });
-                        taken += 1;
-                    }
+        match Self::status_at(&ev, now) {
+            EventStatus::Closed => {}
+            EventStatus::Cancelled => return Err(Error::EventCancelled),
+            _ => return Err(Error::EventNotClosed),
+        }
+        if Self::has_persistent(env, &DataKey::CertificateFor(event_id, attendee.clone())) {
+            return Err(Error::CertificateAlreadyClaimed);
+        }
+        let info = match Self::find_attendee(env, event_id, &attendee) {
+            Some((_, info)) if info.active => info,
+            _ => return Err(Error::AttendeeNotRegistered),
+        };
+        if !Self::meets_min_duration(&ev, &info) { return Err(Error::BelowMinimumDuration); }
+        let cert_id = Self::next_cert_id(env);
+        let cert = CertificateData {
+            id: cert_id,
+            event_id,
+            owner: attendee.clone(),
+            registered_at: info.registered_at,
+            duration_secs: info.duration_secs,
+            issued_at: now,
+        };
+        Self::write_persistent(env, &DataKey::CertificateById(cert_id), &cert);
+        Self::write_persistent(env, &DataKey::CertificateFor(event_id, attendee.clone()), &cert_id);
+        let owned: u64 = Self::read_persistent(env, &DataKey::CertificatesCount(attendee.clone())).unwrap_or(0);
+        Self::write_persistent(env, &DataKey::CertificateByOwnerIndex(attendee.clone(), owned), &cert_id);
+        Self::write_persistent(env, &DataKey::CertificatesCount(attendee.clone()), &(owned + 1));
+        Self::emit_certificate_minted(env, event_id, attendee, cert_id);
+        Ok(cert_id)
+    }
+
+    pub fn get_certificate(env: &Env, cert_id: u64) -> Option<CertificateData> {
+        Self::read_persistent(env, &DataKey::CertificateById(cert_id))
+    }
+
+    pub fn certificate_for(env: &Env, event_id: u64, attendee: Address) -> Option<u64> {
+        Self::read_persistent(env, &DataKey::CertificateFor(event_id, attendee))
+    }
+
+    pub fn certificate_count(env: &Env, owner: Address) -> u64 {
+        Self::read_persistent(env, &DataKey::CertificatesCount(owner)).unwrap_or(0)
+    }
+
+    pub fn list_certificates(env: &Env, owner: Address, cursor: u64, limit: u32) -> CertificatePage {
+        let mut items: Vec<CertificateData> = Vec::new(env);
+        let count = Self::certificate_count(env, owner.clone());
+        let mut i = cursor;
+        while i < count && items.len() < limit {
+            let cert_id: Option<u64> = Self::read_persistent(env, &DataKey::CertificateByOwnerIndex(owner.clone(), i));
+            if let Some(cert) = cert_id.and_then(|id| Self::get_certificate(env, id)) {
+                items.push_back(cert);
+            }
+            i += 1;
+        }
+        CertificatePage { items, next_cursor: if i < count { Some(i) } else { None } }
+    }
+
+    /// Metadata URI of a certificate: the certificate base URI followed by
+    /// the certificate id.
+    pub fn certificate_uri(env: &Env, cert_id: u64) -> Result<String, Error> {
+        if !Self::has_persistent(env, &DataKey::CertificateById(cert_id)) { return Err(Error::CertificateNotFound); }
+        let base: String = env.storage().instance().get(CERT_BASE_URI_KEY).unwrap_or(String::from_str(env, ""));
+        let base_len = base.len() as usize;
+        let mut buf = [0u8; MAX_URI_LEN as usize + 20];
+        base.copy_into_slice(&mut buf[..base_len]);
+        let mut digits = [0u8; 20];
+        let mut n = cert_id;
+        let mut d = 0usize;
+        loop {
+            digits[d] = b'0' + (n % 10) as u8;
+            d += 1;
+            n /= 10;
+            if n == 0 { break; }
+        }
+        for k in 0..d {
+            buf[base_len + k] = digits[d - 1 - k];
+        }
+        Ok(String::from_bytes(env, &buf[..base_len + d]))
+    }
+
+    fn next_cert_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(NEXT_CERT_ID_KEY).unwrap_or(1u64);
+        env.storage().instance().set(NEXT_CERT_ID_KEY, &(id + 1));
+        id
+    }
+
+    fn find_attendee(env: &Env, event_id: u64, attendee: &Address) -> Option<(u64, AttendeeInfo)> {
+        let i: u64 = Self::read_persistent(env, &DataKey::AttendeeIndex(event_id, attendee.clone()))?;
+        let info: AttendeeInfo = Self::read_persistent(env, &DataKey::AttendeeByIndex(event_id, i))?;
+        Some((i, info))
+    }
+
+    fn validate_window(start_ts: u64, end_ts: u64) -> Result<(), Error> {
+        if start_ts == 0 || end_ts == 0 || end_ts <= start_ts { return Err(Error::InvalidWindow); }
+        Ok(())
+    }
+
+    /// Last second attendees may register, i.e. `end_ts` plus the late grace.
+    fn open_until(ev: &EventData) -> u64 {
+        ev.end_ts.saturating_add(ev.late_grace_secs)
+    }
+
+    fn is_long(ev: &EventData) -> bool {
+        Self::open_until(ev) - ev.start_ts > MAX_EVENT_DURATION_SECS
+    }
+
+    /// Adds an event to the day index of `start_ts`, and to the long events
+    /// if it stays open (until `open_until`) longer than
+    /// `MAX_EVENT_DURATION_SECS`.
+    fn index_event_day(env: &Env, event_id: u64, start_ts: u64, open_until: u64) {
+        let day = start_ts / DAY_SECS;
+        let mut slots: Vec<(u64, u64)> = Self::read_persistent(env, &DataKey::EventsByDay(day)).unwrap_or(Vec::new(env));
+        let mut pos = slots.len();
+        for (k, (ts, _)) in slots.iter().enumerate() {
+            if start_ts < ts {
+                pos = k as u32;
+                break;
+            }
+        }
+        slots.insert(pos, (start_ts, event_id));
+        Self::write_persistent(env, &DataKey::EventsByDay(day), &slots);
+        let last_day: u64 = env.storage().instance().get(LAST_DAY_KEY).unwrap_or(0);
+        if day > last_day { env.storage().instance().set(LAST_DAY_KEY, &day); }
+        if open_until - start_ts > MAX_EVENT_DURATION_SECS {
+            // Drop entries that already closed so the list stays short.
+            let now = env.ledger().timestamp();
+            let mut long: Vec<u64> = Vec::new(env);
+            for id in Self::read_persistent::<Vec<u64>>(env, &DataKey::LongEvents).unwrap_or(Vec::new(env)).iter() {
+                if let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id))
+                    && ev.status != EventStatus::Cancelled && Self::open_until(&ev) >= now {
+                    long.push_back(id);
                 }
             }
-            if id == 1 { break; }
-            id -= 1;
+            long.push_back(event_id);
+            Self::write_persistent(env, &DataKey::LongEvents, &long);
+        }
+    }
+
+    fn unindex_event_day(env: &Env, event_id: u64, start_ts: u64, open_until: u64) {
+        let day = start_ts / DAY_SECS;
+        let mut slots: Vec<(u64, u64)> = Self::read_persistent(env, &DataKey::EventsByDay(day)).unwrap_or(Vec::new(env));
+        if let Some(pos) = slots.first_index_of((start_ts, event_id)) {
+            slots.remove(pos);
+            Self::write_persistent(env, &DataKey::EventsByDay(day), &slots);
+        }
+        if open_until - start_ts > MAX_EVENT_DURATION_SECS {
+            let mut long: Vec<u64> = Self::read_persistent(env, &DataKey::LongEvents).unwrap_or(Vec::new(env));
+            if let Some(pos) = long.first_index_of(event_id) {
+                long.remove(pos);
+                Self::write_persistent(env, &DataKey::LongEvents, &long);
+            }
+        }
+    }
+
+    /// Walks the day index from `from_day` to `to_day` (inclusive), keeping
+    /// events accepted by `keep`. The cursor encodes `day << 16 | offset`.
+    fn scan_days(env: &Env, from_day: u64, to_day: u64, cursor: u64, limit: u32, keep: impl Fn(&EventData) -> bool) -> EventPage {
+        let now = env.ledger().timestamp();
+        let mut items: Vec<EventSummary> = Vec::new(env);
+        let (mut day, mut offset) = if cursor == 0 || cursor >> 16 < from_day {
+            (from_day, 0u32)
+        } else {
+            (cursor >> 16, (cursor & 0xFFFF) as u32)
+        };
+        let mut scanned = 0u32;
+        while day <= to_day && items.len() < limit && scanned < MAX_PAGE_SCAN {
+            let slots: Vec<(u64, u64)> = Self::read_persistent(env, &DataKey::EventsByDay(day)).unwrap_or(Vec::new(env));
+            while offset < slots.len() && items.len() < limit {
+                let (_, id) = slots.get_unchecked(offset);
+                if let Some(ev) = Self::read_persistent::<EventData>(env, &DataKey::EventById(id)) && keep(&ev) {
+                    items.push_back(Self::summary(id, ev, now));
+                }
+                offset += 1;
+            }
+            if offset < slots.len() { break; }
+            day += 1;
+            offset = 0;
+            scanned += 1;
         }
-        out
+        EventPage { items, next_cursor: if day <= to_day { Some((day << 16) | offset as u64) } else { None } }
+    }
+
+    fn next_series_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(NEXT_SERIES_ID_KEY).unwrap_or(1u64);
+        env.storage().instance().set(NEXT_SERIES_ID_KEY, &(id + 1));
+        id
+    }
+
+    fn store_series(env: &Env, series_id: u64, series: SeriesData) {
+        Self::write_persistent(env, &DataKey::SeriesById(series_id), &series);
+        Self::emit_series_created(env, series_id, &series);
     }
 
     fn next_event_id(env: &Env) -> u64 {
@@ -254,13 +1281,459 @@ impl PresenceEvents {
         id
     }
 
-    fn require_admin(env: &Env) {
-        let admin: Address = env.storage().instance().get(ADMIN_KEY).expect("admin not set");
+    fn load_event(env: &Env, event_id: u64) -> Result<EventData, Error> {
+        Self::read_persistent(env, &DataKey::EventById(event_id)).ok_or(Error::EventNotFound)
+    }
+
+    fn check_window(ev: &EventData, ts: u64) -> Result<(), Error> {
+        if ev.status == EventStatus::Cancelled { return Err(Error::EventCancelled); }
+        let early_from = ev.start_ts.saturating_sub(ev.early_checkin_secs);
+        if ts < early_from { return Err(Error::TooEarly); }
+        if ts > ev.end_ts.saturating_add(ev.late_grace_secs) { return Err(Error::OutsideWindow); }
+        Ok(())
+    }
+
+    /// Whether `code` is the rotating code of `window` or of the one before,
+    /// i.e. hashes to `anchor` in `window` or `window - 1` steps.
+    fn is_window_code(env: &Env, code: BytesN<32>, anchor: &BytesN<32>, window: u64) -> bool {
+        let mut hash = code;
+        for step in 1..=window {
+            hash = env.crypto().sha256(&hash.into()).into();
+            if step + 1 >= window && hash == *anchor { return true; }
+        }
+        false
+    }
+
+    /// Marks `attendee` present and appends it to the attendee list. `by` is
+    /// the operator, attendee or voucher signer reported in the event.
+    /// Returns `false` when the attendee was already registered, and an error
+    /// when the enrollment policy does not admit the attendee.
+    fn record_presence(env: &Env, event_id: u64, ev: &EventData, attendee: &Address, now: u64, by: &Address) -> Result<bool, Error> {
+        let already: bool = Self::read_persistent(env, &DataKey::Presence(event_id, attendee.clone())).unwrap_or(false);
+        if already { return Ok(false); }
+        if ev.invite_only {
+            let status: Option<EnrollmentStatus> = Self::read_persistent(env, &DataKey::Enrollment(event_id, attendee.clone()));
+            if status != Some(EnrollmentStatus::Enrolled) { return Err(Error::NotEnrolled); }
+        }
+        let active: u64 = Self::read_persistent(env, &DataKey::ActiveAttendeesCount(event_id)).unwrap_or(0);
+        if ev.max_capacity > 0 && active >= ev.max_capacity as u64 { return Err(Error::CapacityReached); }
+        Self::write_persistent(env, &DataKey::ActiveAttendeesCount(event_id), &(active + 1));
+        Self::write_persistent(env, &DataKey::Presence(event_id, attendee.clone()), &true);
+        let info = AttendeeInfo {
+            address: attendee.clone(),
+            registered_at: now,
+            active: true,
+            checked_out_at: None,
+            duration_secs: 0,
+        };
+        // A re-registered attendee takes back its previous (inactive) row.
+        let index: Option<u64> = Self::read_persistent(env, &DataKey::AttendeeIndex(event_id, attendee.clone()));
+        if let Some(i) = index {
+            Self::write_persistent(env, &DataKey::AttendeeByIndex(event_id, i), &info);
+        } else {
+            let count: u64 = Self::read_persistent(env, &DataKey::AttendeesCount(event_id)).unwrap_or(0);
+            Self::write_persistent(env, &DataKey::AttendeeByIndex(event_id, count), &info);
+            Self::write_persistent(env, &DataKey::AttendeeIndex(event_id, attendee.clone()), &count);
+            Self::write_persistent(env, &DataKey::AttendeesCount(event_id), &(count + 1));
+        }
+        Self::index_attendee_event(env, attendee, event_id);
+        Self::emit_presence_registered(env, event_id, attendee.clone(), now, by.clone());
+        Ok(true)
+    }
+
+    fn index_attendee_event(env: &Env, attendee: &Address, event_id: u64) {
+        let count: u64 = Self::read_persistent(env, &DataKey::AttendeeEventsCount(attendee.clone())).unwrap_or(0);
+        Self::write_persistent(env, &DataKey::AttendeeEventByIndex(attendee.clone(), count), &event_id);
+        Self::write_persistent(env, &DataKey::AttendeeEventPosition(attendee.clone(), event_id), &count);
+        Self::write_persistent(env, &DataKey::AttendeeEventsCount(attendee.clone()), &(count + 1));
+    }
+
+    /// Removes `event_id` from the attendee's history by moving the last
+    /// entry into its slot.
+    fn unindex_attendee_event(env: &Env, attendee: &Address, event_id: u64) {
+        let Some(pos) = Self::read_persistent::<u64>(env, &DataKey::AttendeeEventPosition(attendee.clone(), event_id)) else {
+            return;
+        };
+        let count: u64 = Self::read_persistent(env, &DataKey::AttendeeEventsCount(attendee.clone())).unwrap_or(0);
+        let last = count.saturating_sub(1);
+        if pos != last {
+            let moved: u64 = Self::read_persistent(env, &DataKey::AttendeeEventByIndex(attendee.clone(), last)).unwrap_or(0);
+            Self::write_persistent(env, &DataKey::AttendeeEventByIndex(attendee.clone(), pos), &moved);
+            Self::write_persistent(env, &DataKey::AttendeeEventPosition(attendee.clone(), moved), &pos);
+        }
+        Self::remove_persistent(env, &DataKey::AttendeeEventByIndex(attendee.clone(), last));
+        Self::remove_persistent(env, &DataKey::AttendeeEventPosition(attendee.clone(), event_id));
+        Self::write_persistent(env, &DataKey::AttendeeEventsCount(attendee.clone()), &last);
+    }
+
+    fn enroll(env: &Env, event_id: u64, ev: &EventData, attendee: &Address) -> EnrollmentStatus {
+        let enrolled: u32 = Self::read_persistent(env, &DataKey::EnrolledCount(event_id)).unwrap_or(0);
+        let status = if ev.max_capacity == 0 || enrolled < ev.max_capacity {
+            Self::write_persistent(env, &DataKey::EnrolledCount(event_id), &(enrolled + 1));
+            EnrollmentStatus::Enrolled
+        } else {
+            let tail: u64 = Self::read_persistent(env, &DataKey::WaitlistTail(event_id)).unwrap_or(0);
+            Self::write_persistent(env, &DataKey::WaitlistByIndex(event_id, tail), attendee);
+            Self::write_persistent(env, &DataKey::WaitlistPosition(event_id, attendee.clone()), &tail);
+            Self::write_persistent(env, &DataKey::WaitlistTail(event_id), &(tail + 1));
+            EnrollmentStatus::Waitlisted
+        };
+        Self::write_persistent(env, &DataKey::Enrollment(event_id, attendee.clone()), &status);
+        Self::emit_enrolled(env, event_id, attendee.clone(), status);
+        status
+    }
+
+    /// Enrolls attendees still holding their waitlist slot, in order, while
+    /// `ev` has free seats. Examines at most `MAX_PAGE_SCAN` slots and
+    /// returns the number promoted.
+    fn fill_from_waitlist(env: &Env, event_id: u64, ev: &EventData) -> u32 {
+        let mut head: u64 = Self::read_persistent(env, &DataKey::WaitlistHead(event_id)).unwrap_or(0);
+        let tail: u64 = Self::read_persistent(env, &DataKey::WaitlistTail(event_id)).unwrap_or(0);
+        let mut enrolled: u32 = Self::read_persistent(env, &DataKey::EnrolledCount(event_id)).unwrap_or(0);
+        let mut promoted = 0u32;
+        let mut scanned = 0u32;
+        while head < tail && scanned < MAX_PAGE_SCAN && (ev.max_capacity == 0 || enrolled < ev.max_capacity) {
+            let next: Option<Address> = Self::read_persistent(env, &DataKey::WaitlistByIndex(event_id, head));
+            Self::remove_persistent(env, &DataKey::WaitlistByIndex(event_id, head));
+            let slot = head;
+            head += 1;
+            scanned += 1;
+            let Some(addr) = next else { continue; };
+            let position_key = DataKey::WaitlistPosition(event_id, addr.clone());
+            if Self::read_persistent::<u64>(env, &position_key) != Some(slot) { continue; }
+            Self::remove_persistent(env, &position_key);
+            let status: Option<EnrollmentStatus> = Self::read_persistent(env, &DataKey::Enrollment(event_id, addr.clone()));
+            if status == Some(EnrollmentStatus::Waitlisted) {
+                Self::write_persistent(env, &DataKey::Enrollment(event_id, addr.clone()), &EnrollmentStatus::Enrolled);
+                enrolled += 1;
+                promoted += 1;
+                Self::emit_waitlist_promoted(env, event_id, addr);
+            }
+        }
+        Self::write_persistent(env, &DataKey::EnrolledCount(event_id), &enrolled);
+        Self::write_persistent(env, &DataKey::WaitlistHead(event_id), &head);
+        promoted
+    }
+
+    fn record_checkout(env: &Env, event_id: u64, ev: &EventData, attendee: &Address, now: u64, operator: &Address) -> Result<(), Error> {
+        let (i, mut info) = match Self::find_attendee(env, event_id, attendee) {
+            Some((i, info)) if info.active => (i, info),
+            _ => return Err(Error::AttendeeNotRegistered),
+        };
+        if info.checked_out_at.is_some() { return Err(Error::AlreadyCheckedOut); }
+        let from = info.registered_at.max(ev.start_ts);
+        let until = now.min(ev.end_ts);
+        info.checked_out_at = Some(now);
+        info.duration_secs = until.saturating_sub(from);
+        Self::write_persistent(env, &DataKey::AttendeeByIndex(event_id, i), &info);
+        Self::emit_checkout_registered(env, event_id, attendee.clone(), now, info.duration_secs, operator.clone());
+        Ok(())
+    }
+
+    fn meets_min_duration(ev: &EventData, info: &AttendeeInfo) -> bool {
+        ev.min_duration_secs == 0 || (info.checked_out_at.is_some() && info.duration_secs >= ev.min_duration_secs)
+    }
+
+    fn status_at(ev: &EventData, now: u64) -> EventStatus {
+        if ev.status == EventStatus::Cancelled { return EventStatus::Cancelled; }
+        if now > ev.end_ts.saturating_add(ev.late_grace_secs) { return EventStatus::Closed; }
+        if now >= ev.start_ts { return EventStatus::Open; }
+        EventStatus::Scheduled
+    }
+
+    fn summary(id: u64, ev: EventData, now: u64) -> EventSummary {
+        let status = Self::status_at(&ev, now);
+        EventSummary { id, name: ev.name, start_ts: ev.start_ts, end_ts: ev.end_ts, status }
+    }
+
+    fn require_not_paused(env: &Env, flag: fn(&PauseStatus) -> bool) -> Result<(), Error> {
+        if flag(&Self::pause_status(env)) { return Err(Error::Paused); }
+        Ok(())
+    }
+
+    /// Stores `status` and reports which flags it raised and lowered.
+    fn apply_pause(env: &Env, status: PauseStatus) {
+        let previous = Self::pause_status(env);
+        env.storage().instance().set(PAUSE_FLAGS_KEY, &status);
+        let raised = status.without(&previous);
+        let lowered = previous.without(&status);
+        if raised.any() { Self::emit_paused(env, raised); }
+        if lowered.any() { Self::emit_unpaused(env, lowered); }
+    }
+
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(ADMIN_KEY).ok_or(Error::AdminNotSet)?;
         admin.require_auth();
+        Ok(())
+    }
+
+    fn is_admin(env: &Env, wallet: &Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(ADMIN_KEY);
+        admin.as_ref() == Some(wallet)
+    }
+
+    /// The event creator (while still a supervisor) or the admin.
+    fn require_event_manager(env: &Env, ev: &EventData, operator: &Address) -> Result<(), Error> {
+        operator.require_auth();
+        if Self::is_admin(env, operator) { return Ok(()); }
+        if ev.creator == *operator && Self::is_supervisor(env, operator)? { return Ok(()); }
+        Err(Error::NotEventOrganizer)
+    }
+
+    fn is_organizer(ev: &EventData, wallet: &Address) -> bool {
+        ev.creator == *wallet || ev.organizers.contains(wallet)
+    }
+
+    /// An organizer of the event (creator or co-organizer, while still a
+    /// supervisor) or the admin.
+    fn require_organizer(env: &Env, ev: &EventData, operator: &Address) -> Result<(), Error> {
+        operator.require_auth();
+        if Self::is_admin(env, operator) { return Ok(()); }
+        if !Self::is_organizer(ev, operator) { return Err(Error::NotEventOrganizer); }
+        if !Self::is_supervisor(env, operator)? { return Err(Error::NotSupervisor); }
+        Ok(())
+    }
+
+    fn require_supervisor(env: &Env, operator: &Address) -> Result<(), Error> {
+        operator.require_auth();
+        if !Self::is_supervisor(env, operator)? { return Err(Error::NotSupervisor); }
+        Ok(())
+    }
+
+    fn is_supervisor(env: &Env, wallet: &Address) -> Result<bool, Error> {
+        let owner_rules: Address = env.storage().instance().get(OWNER_RULES_KEY).ok_or(Error::OwnerRulesNotSet)?;
+        Ok(OwnerRulesClient::new(env, &owner_rules).has_role(wallet, &ROLE_SUPERVISOR))
+    }
+
+    fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() { Self::bump_persistent(env, key); }
+        value
+    }
+
+    fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::bump_persistent(env, key);
+        Self::bump_instance(env);
+    }
+
+    fn has_persistent(env: &Env, key: &DataKey) -> bool {
+        env.storage().persistent().has(key)
+    }
+
+    fn remove_persistent(env: &Env, key: &DataKey) {
+        env.storage().persistent().remove(key);
+    }
+
+    fn bump_persistent(env: &Env, key: &DataKey) {
+        env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn bump_instance(env: &Env) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Raises every pause flag.
+    pub fn pause(env: &Env) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        Self::apply_pause(env, PauseStatus::all(true));
+        Ok(())
+    }
+
+    pub fn unpause(env: &Env) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        Self::apply_pause(env, PauseStatus::all(false));
+        Ok(())
+    }
+
+    /// Sets each pause flag individually:
+    /// - `event_creation`: creating, editing, rescheduling, configuring and
+    ///   cancelling events and series, including their organizers.
+    /// - `registration`: enrollment, presence and its removal, checkout,
+    ///   check-in and vouchers.
+    /// - `issuance`: opening and closing check-in sessions and registering
+    ///   and removing voucher keys.
+    /// - `claims`: certificate claims.
+    /// - `admin`: `propose_admin` and `accept_admin`.
+    ///
+    /// Cancelling an admin proposal stays available.
+    pub fn set_pause(env: &Env, status: PauseStatus) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        Self::apply_pause(env, status);
+        Ok(())
+    }
+
+    pub fn pause_status(env: &Env) -> PauseStatus {
+        env.storage().instance().get(PAUSE_FLAGS_KEY).unwrap_or(PauseStatus::all(false))
+    }
+
+    /// First step of an admin handover. Nothing changes until `new_admin`
+    /// calls `accept_admin` before `expires_at`.
+    pub fn propose_admin(env: &Env, new_admin: Address, expires_at: u64) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.admin)?;
+        Self::require_admin(env)?;
+        if expires_at <= env.ledger().timestamp() { return Err(Error::InvalidExpiry); }
+        let admin: Address = env.storage().instance().get(ADMIN_KEY).ok_or(Error::AdminNotSet)?;
+        env.storage().instance().set(PENDING_ADMIN_KEY, &PendingAdmin { new_admin: new_admin.clone(), expires_at });
+        Self::emit_admin_proposed(env, admin, new_admin, expires_at);
+        Ok(())
+    }
+
+    pub fn accept_admin(env: &Env) -> Result<(), Error> {
+        Self::require_not_paused(env, |p| p.admin)?;
+        let pending: PendingAdmin = env.storage().instance().get(PENDING_ADMIN_KEY).ok_or(Error::NoPendingAdmin)?;
+        if env.ledger().timestamp() > pending.expires_at { return Err(Error::AdminProposalExpired); }
+        pending.new_admin.require_auth();
+        let old_admin: Address = env.storage().instance().get(ADMIN_KEY).ok_or(Error::AdminNotSet)?;
+        env.storage().instance().remove(PENDING_ADMIN_KEY);
+        env.storage().instance().set(ADMIN_KEY, &pending.new_admin);
+        Self::emit_admin_transferred(env, old_admin, pending.new_admin);
+        Ok(())
+    }
+
+    pub fn cancel_admin_proposal(env: &Env) -> Result<(), Error> {
+        Self::require_admin(env)?;
+        let pending: PendingAdmin = env.storage().instance().get(PENDING_ADMIN_KEY).ok_or(Error::NoPendingAdmin)?;
+        env.storage().instance().remove(PENDING_ADMIN_KEY);
+        Self::emit_admin_proposal_cancelled(env, pending.new_admin);
+        Ok(())
+    }
+
+    pub fn get_admin(env: &Env) -> Result<Address, Error> {
+        env.storage().instance().get(ADMIN_KEY).ok_or(Error::AdminNotSet)
+    }
+
+    /// Returns the outstanding proposal, if any. An expired proposal stays
+    /// visible until it is cancelled or replaced.
+    pub fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+        env.storage().instance().get(PENDING_ADMIN_KEY)
+    }
+
+    fn emit_owner_rules_updated(env: &Env, contract: Address) {
+        env.events().publish((symbol_short!("OwnRules"),), (EVENT_VERSION, contract));
+    }
+
+    fn emit_admin_transferred(env: &Env, old_admin: Address, new_admin: Address) {
+        env.events().publish((symbol_short!("AdminXfer"), old_admin, new_admin), (EVENT_VERSION,));
+    }
+
+    fn emit_paused(env: &Env, flags: PauseStatus) {
+        env.events().publish((symbol_short!("Paused"),), (EVENT_VERSION, flags));
+    }
+
+    fn emit_unpaused(env: &Env, flags: PauseStatus) {
+        env.events().publish((symbol_short!("Unpaused"),), (EVENT_VERSION, flags));
+    }
+
+    fn emit_admin_proposed(env: &Env, admin: Address, new_admin: Address, expires_at: u64) {
+        env.events().publish((symbol_short!("AdmProp"), admin, new_admin), (EVENT_VERSION, expires_at));
+    }
+
+    fn emit_admin_proposal_cancelled(env: &Env, new_admin: Address) {
+        env.events().publish((symbol_short!("AdmCancel"), new_admin), (EVENT_VERSION,));
+    }
+
+    fn emit_event_created(env: &Env, event_id: u64, ev: &EventData) {
+        env.events().publish(
+            (symbol_short!("EvCreate"), event_id, ev.creator.clone()),
+            (EVENT_VERSION, ev.name.clone(), ev.start_ts, ev.end_ts),
+        );
+    }
+
+    fn emit_series_created(env: &Env, series_id: u64, series: &SeriesData) {
+        env.events().publish(
+            (symbol_short!("SerCreate"), series_id, series.creator.clone()),
+            (EVENT_VERSION, series.name.clone(), series.sessions.clone()),
+        );
+    }
+
+    fn emit_event_updated(env: &Env, event_id: u64, operator: Address, name: String) {
+        env.events().publish((symbol_short!("EvUpdate"), event_id, operator), (EVENT_VERSION, name));
+    }
+
+    fn emit_event_rescheduled(env: &Env, event_id: u64, operator: Address, start_ts: u64, end_ts: u64) {
+        env.events().publish((symbol_short!("EvResched"), event_id, operator), (EVENT_VERSION, start_ts, end_ts));
+    }
+
+    fn emit_event_cancelled(env: &Env, event_id: u64, operator: Address) {
+        env.events().publish((symbol_short!("EvCancel"), event_id, operator), (EVENT_VERSION,));
+    }
+
+    fn emit_checkin_window_updated(env: &Env, event_id: u64, operator: Address, early_checkin_secs: u64, late_grace_secs: u64) {
+        env.events().publish(
+            (symbol_short!("CkWindow"), event_id, operator),
+            (EVENT_VERSION, early_checkin_secs, late_grace_secs),
+        );
+    }
+
+    fn emit_default_checkin_window_updated(env: &Env, early_checkin_secs: u64, late_grace_secs: u64) {
+        env.events().publish((symbol_short!("DefWindow"),), (EVENT_VERSION, early_checkin_secs, late_grace_secs));
+    }
+
+    fn emit_organizer_added(env: &Env, event_id: u64, organizer: Address, operator: Address) {
+        env.events().publish((symbol_short!("OrgAdd"), event_id, organizer), (EVENT_VERSION, operator));
+    }
+
+    fn emit_organizer_removed(env: &Env, event_id: u64, organizer: Address, operator: Address) {
+        env.events().publish((symbol_short!("OrgRem"), event_id, organizer), (EVENT_VERSION, operator));
+    }
+
+    fn emit_presence_registered(env: &Env, event_id: u64, attendee: Address, registered_at: u64, by: Address) {
+        env.events().publish((symbol_short!("PresReg"), event_id, attendee), (EVENT_VERSION, registered_at, by));
+    }
+
+    fn emit_checkout_registered(env: &Env, event_id: u64, attendee: Address, checked_out_at: u64, duration_secs: u64, operator: Address) {
+        env.events().publish(
+            (symbol_short!("PresOut"), event_id, attendee),
+            (EVENT_VERSION, checked_out_at, duration_secs, operator),
+        );
+    }
+
+    fn emit_min_duration_updated(env: &Env, event_id: u64, operator: Address, min_duration_secs: u64) {
+        env.events().publish((symbol_short!("MinDur"), event_id, operator), (EVENT_VERSION, min_duration_secs));
+    }
+
+    fn emit_enrollment_policy_updated(env: &Env, event_id: u64, operator: Address, invite_only: bool, max_capacity: u32) {
+        env.events().publish((symbol_short!("EnrPolicy"), event_id, operator), (EVENT_VERSION, invite_only, max_capacity));
+    }
+
+    fn emit_enrolled(env: &Env, event_id: u64, attendee: Address, status: EnrollmentStatus) {
+        env.events().publish((symbol_short!("Enroll"), event_id, attendee), (EVENT_VERSION, status));
+    }
+
+    fn emit_enrollment_withdrawn(env: &Env, event_id: u64, attendee: Address) {
+        env.events().publish((symbol_short!("EnrollWd"), event_id, attendee), (EVENT_VERSION,));
+    }
+
+    fn emit_waitlist_promoted(env: &Env, event_id: u64, attendee: Address) {
+        env.events().publish((symbol_short!("WaitProm"), event_id, attendee), (EVENT_VERSION,));
+    }
+
+    fn emit_presence_removed(env: &Env, event_id: u64, attendee: Address, operator: Address) {
+        env.events().publish((symbol_short!("PresRem"), event_id, attendee), (EVENT_VERSION, operator));
+    }
+
+    fn emit_checkin_opened(env: &Env, event_id: u64, operator: Address, verifier: CheckInVerifier) {
+        env.events().publish((symbol_short!("CkOpen"), event_id, operator), (EVENT_VERSION, verifier));
+    }
+
+    fn emit_checkin_closed(env: &Env, event_id: u64, operator: Address) {
+        env.events().publish((symbol_short!("CkClose"), event_id, operator), (EVENT_VERSION,));
+    }
+
+    fn emit_voucher_key_set(env: &Env, supervisor: Address, public_key: BytesN<32>) {
+        env.events().publish((symbol_short!("VchKey"), supervisor), (EVENT_VERSION, public_key));
+    }
+
+    fn emit_voucher_key_removed(env: &Env, supervisor: Address) {
+        env.events().publish((symbol_short!("VchKeyRm"), supervisor), (EVENT_VERSION,));
+    }
+
+    fn emit_certificate_minted(env: &Env, event_id: u64, owner: Address, cert_id: u64) {
+        env.events().publish((symbol_short!("CertMint"), event_id, owner), (EVENT_VERSION, cert_id));
     }
 
-    pub fn transfer_admin(env: &Env, new_admin: Address) {
-        Self::require_admin(env);
-        env.storage().instance().set(ADMIN_KEY, &new_admin);
+    fn emit_certificate_base_uri_updated(env: &Env, new_uri: String) {
+        env.events().publish((symbol_short!("CertURI"),), (EVENT_VERSION, new_uri));
     }
 }
@@ -0,0 +1,71 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, IntoVal, Val, Vec};
+
+use super::{advance, emitted, setup, HOUR, NOW};
+use crate::contract::EVENT_VERSION;
+use crate::Error;
+
+#[test]
+fn admin_not_set() {
+    let s = setup();
+    s.env.as_contract(&s.client.address, || s.env.storage().instance().remove(&symbol_short!("ADMIN")));
+    assert_eq!(s.client.try_get_admin(), Err(Ok(Error::AdminNotSet)));
+}
+
+#[test]
+fn no_pending_admin() {
+    let s = setup();
+    assert_eq!(s.client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+}
+
+#[test]
+fn admin_proposal_expired() {
+    let s = setup();
+    s.client.propose_admin(&Address::generate(&s.env), &(NOW + 10));
+    advance(&s.env, 11);
+    assert_eq!(s.client.try_accept_admin(), Err(Ok(Error::AdminProposalExpired)));
+}
+
+#[test]
+fn invalid_expiry() {
+    let s = setup();
+    assert_eq!(s.client.try_propose_admin(&s.admin, &NOW), Err(Ok(Error::InvalidExpiry)));
+}
+
+#[test]
+fn admin_transfer_emits_versioned_events() {
+    let s = setup();
+    let new_admin = Address::generate(&s.env);
+    s.client.propose_admin(&new_admin, &(NOW + HOUR));
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("AdmProp"), s.admin.clone(), new_admin.clone()).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32, u64) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION, NOW + HOUR));
+
+    s.client.accept_admin();
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("AdminXfer"), s.admin.clone(), new_admin).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32,) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION,));
+}
+
+#[test]
+fn admin_proposal_cancel_emits_versioned_event() {
+    let s = setup();
+    let new_admin = Address::generate(&s.env);
+    s.client.propose_admin(&new_admin, &(NOW + HOUR));
+    s.client.cancel_admin_proposal();
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("AdmCancel"), new_admin).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32,) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION,));
+    assert_eq!(s.client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+}
@@ -0,0 +1,87 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, IntoVal, String, Val, Vec};
+
+use super::{advance, emitted, open_event, setup, HOUR, NOW};
+use crate::contract::EVENT_VERSION;
+use crate::Error;
+
+#[test]
+fn event_not_closed() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    assert_eq!(s.client.try_claim_certificate(&id, &attendee), Err(Ok(Error::EventNotClosed)));
+}
+
+#[test]
+fn certificate_already_claimed() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    advance(&s.env, HOUR + 1);
+    s.client.claim_certificate(&id, &attendee);
+    assert_eq!(s.client.try_claim_certificate(&id, &attendee), Err(Ok(Error::CertificateAlreadyClaimed)));
+}
+
+#[test]
+fn claimed_certificate_is_recorded_and_enumerable() {
+    let s = setup();
+    s.client.set_certificate_base_uri(&String::from_str(&s.env, "https://certs.example/"));
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    advance(&s.env, HOUR + 1);
+    let cert_id = s.client.claim_certificate(&id, &attendee);
+
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("CertMint"), id, attendee.clone()).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32, u64) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION, cert_id));
+
+    let cert = s.client.get_certificate(&cert_id).unwrap();
+    assert_eq!((cert.event_id, cert.owner.clone(), cert.registered_at), (id, attendee.clone(), NOW));
+    assert_eq!(s.client.certificate_for(&id, &attendee), Some(cert_id));
+    assert_eq!(s.client.certificate_count(&attendee), 1);
+    assert_eq!(s.client.list_certificates(&attendee, &0, &10).items.get_unchecked(0).id, cert_id);
+    assert_eq!(s.client.certificate_uri(&cert_id), String::from_str(&s.env, "https://certs.example/1"));
+}
+
+#[test]
+fn removed_attendee_cannot_claim() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    s.client.remove_presence(&id, &s.supervisor, &attendee);
+    advance(&s.env, HOUR + 1);
+    assert_eq!(s.client.try_claim_certificate(&id, &attendee), Err(Ok(Error::AttendeeNotRegistered)));
+    assert_eq!(s.client.certificate_count(&attendee), 0);
+}
+
+#[test]
+fn certificate_not_found() {
+    let s = setup();
+    assert_eq!(s.client.try_certificate_uri(&99), Err(Ok(Error::CertificateNotFound)));
+}
+
+#[test]
+fn uri_too_long() {
+    let s = setup();
+    let uri = String::from_bytes(&s.env, &[b'a'; 201]);
+    assert_eq!(s.client.try_set_certificate_base_uri(&uri), Err(Ok(Error::UriTooLong)));
+}
+
+#[test]
+fn below_minimum_duration() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_min_duration(&id, &s.supervisor, &HOUR);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    advance(&s.env, HOUR + 1);
+    assert_eq!(s.client.try_claim_certificate(&id, &attendee), Err(Ok(Error::BelowMinimumDuration)));
+}
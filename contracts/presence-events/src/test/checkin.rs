@@ -0,0 +1,138 @@
+extern crate std;
+
+use ed25519_dalek::SigningKey;
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, InvokeError};
+
+use super::{advance, open_event, public_key, setup, sign};
+use crate::{CheckInVerifier, Error};
+
+const WINDOW: u64 = 60;
+
+/// Rotating codes of a `len`-step chain: `codes[k]` is shown in window `k`
+/// and `codes[0]` is the anchor.
+fn code_chain(env: &Env, len: u32) -> std::vec::Vec<BytesN<32>> {
+    let mut codes = std::vec![BytesN::from_array(env, &[3; 32])];
+    for _ in 0..len {
+        let next: BytesN<32> = env.crypto().sha256(&codes[0].clone().into()).into();
+        codes.insert(0, next);
+    }
+    codes
+}
+
+fn code(codes: &[BytesN<32>], window: usize) -> Bytes {
+    codes[window].clone().into()
+}
+
+#[test]
+fn no_checkin_session() {
+    let s = setup();
+    let id = open_event(&s);
+    assert_eq!(s.client.try_close_checkin_session(&id, &s.supervisor), Err(Ok(Error::NoCheckInSession)));
+}
+
+#[test]
+fn invalid_proof() {
+    let s = setup();
+    let id = open_event(&s);
+    let codes = code_chain(&s.env, 10);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::RotatingCode(codes[0].clone(), WINDOW));
+    assert_eq!(
+        s.client.try_check_in(&id, &Address::generate(&s.env), &Bytes::from_array(&s.env, &[4; 32])),
+        Err(Ok(Error::InvalidProof))
+    );
+}
+
+#[test]
+fn checkin_session_expired() {
+    let s = setup();
+    let id = open_event(&s);
+    let codes = code_chain(&s.env, 10);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::RotatingCode(codes[0].clone(), 1));
+    advance(&s.env, 720);
+    assert_eq!(
+        s.client.try_check_in(&id, &Address::generate(&s.env), &code(&codes, 1)),
+        Err(Ok(Error::CheckInSessionExpired))
+    );
+}
+
+#[test]
+fn rotating_code_requires_a_window_length() {
+    let s = setup();
+    let id = open_event(&s);
+    let codes = code_chain(&s.env, 10);
+    assert_eq!(
+        s.client.try_open_checkin_session(&id, &s.supervisor, &CheckInVerifier::RotatingCode(codes[0].clone(), 0)),
+        Err(Ok(Error::InvalidWindow))
+    );
+}
+
+#[test]
+fn rotating_code_admits_every_attendee_in_its_window() {
+    let s = setup();
+    let id = open_event(&s);
+    let codes = code_chain(&s.env, 10);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::RotatingCode(codes[0].clone(), WINDOW));
+    let (a1, a2) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.check_in(&id, &a1, &code(&codes, 1));
+    s.client.check_in(&id, &a2, &code(&codes, 1));
+    assert!(s.client.has_presence(&id, &a1));
+    assert!(s.client.has_presence(&id, &a2));
+    assert_eq!(s.client.try_check_in(&id, &a1, &code(&codes, 1)), Err(Ok(Error::AlreadyRegistered)));
+}
+
+#[test]
+fn rotating_code_expires_after_the_next_window() {
+    let s = setup();
+    let id = open_event(&s);
+    let codes = code_chain(&s.env, 10);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::RotatingCode(codes[0].clone(), WINDOW));
+    advance(&s.env, WINDOW);
+    s.client.check_in(&id, &Address::generate(&s.env), &code(&codes, 1));
+    advance(&s.env, WINDOW);
+    let late = Address::generate(&s.env);
+    assert_eq!(s.client.try_check_in(&id, &late, &code(&codes, 1)), Err(Ok(Error::InvalidProof)));
+    assert_eq!(s.client.try_check_in(&id, &late, &code(&codes, 4)), Err(Ok(Error::InvalidProof)));
+    s.client.check_in(&id, &late, &code(&codes, 3));
+    assert!(s.client.has_presence(&id, &late));
+}
+
+#[test]
+fn signed_check_in_registers_the_attendee() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[6; 32]);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::SigningKey(public_key(&s.env, &key)));
+    let nonce = s.client.get_checkin_session(&id).unwrap().nonce;
+    let attendee = Address::generate(&s.env);
+    let proof = Bytes::from_array(&s.env, &sign(&key, &s.client.checkin_message(&id, &attendee, &nonce)));
+    s.client.check_in(&id, &attendee, &proof);
+    assert!(s.client.has_presence(&id, &attendee));
+}
+
+#[test]
+fn proof_already_used() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[6; 32]);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::SigningKey(public_key(&s.env, &key)));
+    let nonce = s.client.get_checkin_session(&id).unwrap().nonce;
+    let attendee = Address::generate(&s.env);
+    let proof = Bytes::from_array(&s.env, &sign(&key, &s.client.checkin_message(&id, &attendee, &nonce)));
+    s.client.check_in(&id, &attendee, &proof);
+    s.client.remove_presence(&id, &s.supervisor, &attendee);
+    assert_eq!(s.client.try_check_in(&id, &attendee, &proof), Err(Ok(Error::ProofAlreadyUsed)));
+}
+
+#[test]
+fn signed_proof_is_bound_to_its_attendee() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[6; 32]);
+    s.client.open_checkin_session(&id, &s.supervisor, &CheckInVerifier::SigningKey(public_key(&s.env, &key)));
+    let nonce = s.client.get_checkin_session(&id).unwrap().nonce;
+    let attendee = Address::generate(&s.env);
+    let proof = Bytes::from_array(&s.env, &sign(&key, &s.client.checkin_message(&id, &attendee, &nonce)));
+    let other = Address::generate(&s.env);
+    assert_eq!(s.client.try_check_in(&id, &other, &proof), Err(Err(InvokeError::Abort)));
+    assert!(!s.client.has_presence(&id, &other));
+}
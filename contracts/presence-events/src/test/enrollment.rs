@@ -0,0 +1,120 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+use super::{open_event, setup};
+use crate::{EnrollmentStatus, Error};
+
+fn wallets(env: &Env, n: u32) -> Vec<Address> {
+    let mut out = Vec::new(env);
+    for _ in 0..n {
+        out.push_back(Address::generate(env));
+    }
+    out
+}
+
+#[test]
+fn not_enrolled() {
+    let s = setup();
+    let id = open_event(&s);
+    assert_eq!(s.client.try_withdraw_enrollment(&id, &Address::generate(&s.env)), Err(Ok(Error::NotEnrolled)));
+}
+
+#[test]
+fn capacity_reached() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &false, &1);
+    s.client.register_presence(&id, &s.supervisor, &Address::generate(&s.env));
+    assert_eq!(
+        s.client.try_register_presence(&id, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::CapacityReached))
+    );
+}
+
+#[test]
+fn already_enrolled() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.self_enroll(&id, &attendee);
+    assert_eq!(s.client.try_self_enroll(&id, &attendee), Err(Ok(Error::AlreadyEnrolled)));
+}
+
+#[test]
+fn invite_only() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &0);
+    assert_eq!(s.client.try_self_enroll(&id, &Address::generate(&s.env)), Err(Ok(Error::InviteOnly)));
+}
+
+#[test]
+fn enrollment_past_capacity_is_waitlisted_in_order() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &1);
+    let w = wallets(&s.env, 3);
+    s.client.enroll_batch(&id, &s.supervisor, &w);
+    assert_eq!(s.client.enrolled_count(&id), 1);
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(0)), Some(EnrollmentStatus::Enrolled));
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(1)), Some(EnrollmentStatus::Waitlisted));
+
+    s.client.withdraw_enrollment(&id, &w.get_unchecked(0));
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(1)), Some(EnrollmentStatus::Enrolled));
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(2)), Some(EnrollmentStatus::Waitlisted));
+    assert_eq!(s.client.enrolled_count(&id), 1);
+}
+
+#[test]
+fn raising_capacity_promotes_remaining_waitlist_in_order() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &1);
+    let w = wallets(&s.env, 4);
+    s.client.enroll_batch(&id, &s.supervisor, &w);
+    s.client.withdraw_enrollment(&id, &w.get_unchecked(2));
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &3);
+    assert_eq!(s.client.enrolled_count(&id), 3);
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(1)), Some(EnrollmentStatus::Enrolled));
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(2)), None);
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(3)), Some(EnrollmentStatus::Enrolled));
+}
+
+#[test]
+fn withdrawal_does_not_promote_past_a_lowered_capacity() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &2);
+    let w = wallets(&s.env, 3);
+    s.client.enroll_batch(&id, &s.supervisor, &w);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &1);
+    s.client.withdraw_enrollment(&id, &w.get_unchecked(0));
+    assert_eq!(s.client.enrolled_count(&id), 1);
+    assert_eq!(s.client.get_enrollment(&id, &w.get_unchecked(2)), Some(EnrollmentStatus::Waitlisted));
+}
+
+#[test]
+fn promotion_is_bounded_per_call() {
+    let s = setup();
+    s.env.cost_estimate().budget().reset_unlimited();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &1);
+    s.client.enroll_batch(&id, &s.supervisor, &wallets(&s.env, 206));
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &0);
+    assert_eq!(s.client.enrolled_count(&id), 201);
+    assert_eq!(s.client.promote_waitlist(&id), 5);
+    assert_eq!(s.client.enrolled_count(&id), 206);
+    assert_eq!(s.client.promote_waitlist(&id), 0);
+}
+
+#[test]
+fn register_presence_batch_skips_and_reports_rejected_attendees() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_enrollment_policy(&id, &s.supervisor, &true, &0);
+    let (enrolled, stranger) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.enroll_batch(&id, &s.supervisor, &vec![&s.env, enrolled.clone()]);
+    let rejected = s.client.register_presence_batch(&id, &s.supervisor, &vec![&s.env, stranger.clone(), enrolled.clone()]);
+    assert_eq!(rejected, vec![&s.env, stranger.clone()]);
+    assert!(s.client.has_presence(&id, &enrolled));
+    assert!(!s.client.has_presence(&id, &stranger));
+}
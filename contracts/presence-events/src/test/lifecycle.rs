@@ -0,0 +1,150 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    vec, Address, IntoVal, String, Val, Vec,
+};
+
+use super::{advance, emitted, open_event, setup, Setup, HOUR, NOW};
+use crate::contract::{DAY_IN_LEDGERS, EVENT_VERSION, PERSISTENT_BUMP_AMOUNT};
+use crate::{DataKey, Error};
+
+fn ttl(s: &Setup, key: &DataKey) -> u32 {
+    s.env.as_contract(&s.client.address, || s.env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn not_supervisor() {
+    let s = setup();
+    let name = String::from_str(&s.env, "Assembleia");
+    assert_eq!(
+        s.client.try_create_event(&name, &NOW, &(NOW + HOUR), &Address::generate(&s.env)),
+        Err(Ok(Error::NotSupervisor))
+    );
+}
+
+#[test]
+fn owner_rules_not_set() {
+    let s = setup();
+    s.env.as_contract(&s.client.address, || s.env.storage().instance().remove(&symbol_short!("OWN_RULES")));
+    let name = String::from_str(&s.env, "Assembleia");
+    assert_eq!(
+        s.client.try_create_event(&name, &NOW, &(NOW + HOUR), &s.supervisor),
+        Err(Ok(Error::OwnerRulesNotSet))
+    );
+}
+
+#[test]
+fn invalid_window() {
+    let s = setup();
+    let name = String::from_str(&s.env, "Assembleia");
+    assert_eq!(s.client.try_create_event(&name, &NOW, &NOW, &s.supervisor), Err(Ok(Error::InvalidWindow)));
+}
+
+#[test]
+fn event_not_found() {
+    let s = setup();
+    assert_eq!(s.client.try_bump_event(&99), Err(Ok(Error::EventNotFound)));
+}
+
+#[test]
+fn event_cancelled() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.cancel_event(&id, &s.supervisor);
+    assert_eq!(
+        s.client.try_update_event(&id, &s.supervisor, &String::from_str(&s.env, "Nova")),
+        Err(Ok(Error::EventCancelled))
+    );
+}
+
+#[test]
+fn event_closed() {
+    let s = setup();
+    let id = open_event(&s);
+    advance(&s.env, HOUR + 1);
+    assert_eq!(s.client.try_cancel_event(&id, &s.supervisor), Err(Ok(Error::EventClosed)));
+}
+
+#[test]
+fn already_organizer() {
+    let s = setup();
+    let id = open_event(&s);
+    assert_eq!(s.client.try_add_organizer(&id, &s.supervisor, &s.supervisor), Err(Ok(Error::AlreadyOrganizer)));
+}
+
+#[test]
+fn organizer_not_found() {
+    let s = setup();
+    let id = open_event(&s);
+    assert_eq!(
+        s.client.try_remove_organizer(&id, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::OrganizerNotFound))
+    );
+}
+
+#[test]
+fn supervisor_credential_gates_event_management() {
+    let s = setup();
+    let id = open_event(&s);
+    let (a1, a2) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.register_presence(&id, &s.supervisor, &a1);
+    s.client.register_presence_batch(&id, &s.supervisor, &vec![&s.env, a2.clone()]);
+    s.client.remove_presence(&id, &s.supervisor, &a1);
+    assert_eq!(s.client.active_attendees_count(&id), 1);
+
+    s.rules.revoke_credential(&s.supervisor, &2);
+    let name = String::from_str(&s.env, "Assembleia");
+    assert_eq!(
+        s.client.try_create_event(&name, &NOW, &(NOW + HOUR), &s.supervisor),
+        Err(Ok(Error::NotSupervisor))
+    );
+    assert_eq!(s.client.try_register_presence(&id, &s.supervisor, &a1), Err(Ok(Error::NotSupervisor)));
+    assert_eq!(
+        s.client.try_register_presence_batch(&id, &s.supervisor, &vec![&s.env, a1]),
+        Err(Ok(Error::NotSupervisor))
+    );
+    assert_eq!(s.client.try_remove_presence(&id, &s.supervisor, &a2), Err(Ok(Error::NotSupervisor)));
+}
+
+#[test]
+fn create_event_emits_versioned_event() {
+    let s = setup();
+    let id = open_event(&s);
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("EvCreate"), id, s.supervisor.clone()).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32, String, u64, u64) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION, String::from_str(&s.env, "Assembleia"), NOW, NOW + HOUR));
+}
+
+#[test]
+fn bumps_renew_event_and_attendee_ttls() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    assert!(!s.env.as_contract(&s.client.address, || s.env.storage().instance().has(&DataKey::EventById(id))));
+    assert_eq!(ttl(&s, &DataKey::EventById(id)), PERSISTENT_BUMP_AMOUNT);
+
+    s.env.ledger().with_mut(|l| l.sequence_number += 20 * DAY_IN_LEDGERS);
+    let aged = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+    assert_eq!(ttl(&s, &DataKey::EventById(id)), aged);
+    s.client.bump_event(&id);
+    assert_eq!(ttl(&s, &DataKey::EventById(id)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(&s, &DataKey::AttendeeByIndex(id, 0)), aged);
+
+    s.client.bump_attendees(&id, &0, &10);
+    assert_eq!(ttl(&s, &DataKey::AttendeeByIndex(id, 0)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(&s, &DataKey::Presence(id, attendee)), PERSISTENT_BUMP_AMOUNT);
+}
+
+#[test]
+fn recent_entries_keep_their_ttl_on_read() {
+    let s = setup();
+    let id = open_event(&s);
+    s.env.ledger().with_mut(|l| l.sequence_number += DAY_IN_LEDGERS);
+    s.client.bump_event(&id);
+    assert_eq!(ttl(&s, &DataKey::EventById(id)), PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS);
+}
@@ -0,0 +1,120 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+use super::{advance, open_event, setup, Setup, HOUR, NOW};
+use crate::{EventPage, EventStatus};
+
+const DAY: u64 = 24 * HOUR;
+const GRACE: u64 = 600;
+
+fn ids(page: EventPage) -> std::vec::Vec<u64> {
+    page.items.iter().map(|ev| ev.id).collect()
+}
+
+fn create(s: &Setup, start_ts: u64, end_ts: u64) -> u64 {
+    s.client.create_event(&String::from_str(&s.env, "Assembleia"), &start_ts, &end_ts, &s.supervisor)
+}
+
+#[test]
+fn listings_split_at_start_end_and_grace() {
+    let s = setup();
+    let id = create(&s, NOW + HOUR, NOW + 2 * HOUR);
+    s.client.set_checkin_window(&id, &s.supervisor, &0, &GRACE);
+    assert_eq!(ids(s.client.list_upcoming(&0, &10)), [id]);
+    assert!(ids(s.client.list_ongoing(&0, &10)).is_empty());
+
+    advance(&s.env, HOUR);
+    assert!(ids(s.client.list_upcoming(&0, &10)).is_empty());
+    assert_eq!(ids(s.client.list_ongoing(&0, &10)), [id]);
+
+    advance(&s.env, HOUR + GRACE);
+    let ongoing = s.client.list_ongoing(&0, &10);
+    assert_eq!(ongoing.items.get_unchecked(0).status, EventStatus::Open);
+    assert_eq!(ids(ongoing), [id]);
+    assert!(ids(s.client.list_closed(&0, &10)).is_empty());
+
+    advance(&s.env, 1);
+    assert!(ids(s.client.list_ongoing(&0, &10)).is_empty());
+    assert_eq!(ids(s.client.list_closed(&0, &10)), [id]);
+}
+
+#[test]
+fn ongoing_skips_cancelled_events() {
+    let s = setup();
+    let (kept, cancelled) = (create(&s, NOW, NOW + HOUR), create(&s, NOW, NOW + HOUR));
+    s.client.cancel_event(&cancelled, &s.supervisor);
+    assert_eq!(ids(s.client.list_ongoing(&0, &10)), [kept]);
+}
+
+#[test]
+fn ongoing_finds_events_kept_open_by_a_long_grace() {
+    let s = setup();
+    let id = create(&s, NOW, NOW + 30 * DAY);
+    s.client.set_checkin_window(&id, &s.supervisor, &0, &(2 * DAY));
+    advance(&s.env, 31 * DAY);
+    assert_eq!(ids(s.client.list_ongoing(&0, &10)), [id]);
+}
+
+#[test]
+fn event_pages_resume_from_the_returned_cursor() {
+    let s = setup();
+    let (e1, e2, e3) = (create(&s, NOW, NOW + HOUR), create(&s, NOW, NOW + HOUR), create(&s, NOW, NOW + HOUR));
+    let first = s.client.list_events(&0, &2);
+    assert_eq!(first.next_cursor, Some(e3));
+    assert_eq!(ids(first), [e1, e2]);
+    let last = s.client.list_events(&e3, &2);
+    assert_eq!(last.next_cursor, None);
+    assert_eq!(ids(last), [e3]);
+
+    advance(&s.env, HOUR + 1);
+    let first = s.client.list_closed(&0, &2);
+    assert_eq!(first.next_cursor, Some(e1));
+    assert_eq!(ids(first), [e3, e2]);
+    let last = s.client.list_closed(&e1, &2);
+    assert_eq!(last.next_cursor, None);
+    assert_eq!(ids(last), [e1]);
+}
+
+#[test]
+fn attendee_pages_skip_removed_rows_when_filtering() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendees: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&s.env)).collect();
+    for a in &attendees {
+        s.client.register_presence(&id, &s.supervisor, a);
+    }
+    s.client.remove_presence(&id, &s.supervisor, &attendees[1]);
+
+    let first = s.client.list_attendees(&id, &0, &2, &true);
+    assert_eq!(first.next_cursor, None);
+    let active: std::vec::Vec<Address> = first.items.iter().map(|a| a.address).collect();
+    assert_eq!(active, [attendees[0].clone(), attendees[2].clone()]);
+
+    let first = s.client.list_attendees(&id, &0, &2, &false);
+    assert_eq!(first.next_cursor, Some(2));
+    assert!(!first.items.get_unchecked(1).active);
+    let last = s.client.list_attendees(&id, &2, &2, &false);
+    assert_eq!(last.next_cursor, None);
+    assert_eq!(last.items.get_unchecked(0).address, attendees[2]);
+}
+
+#[test]
+fn attendee_history_follows_registrations_and_removals() {
+    let s = setup();
+    let (e1, e2, e3) = (create(&s, NOW, NOW + HOUR), create(&s, NOW, NOW + HOUR), create(&s, NOW, NOW + HOUR));
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&e1, &s.supervisor, &attendee);
+    s.client.register_presence(&e2, &s.supervisor, &attendee);
+    s.client.register_presence_batch(&e3, &s.supervisor, &vec![&s.env, attendee.clone()]);
+    assert_eq!(s.client.attendance_count(&attendee), 3);
+    assert_eq!(ids(s.client.list_events_for_attendee(&attendee, &0, &10)), [e1, e2, e3]);
+
+    // The last entry fills the slot freed by the removal.
+    s.client.remove_presence(&e1, &s.supervisor, &attendee);
+    assert_eq!(s.client.attendance_count(&attendee), 2);
+    let first = s.client.list_events_for_attendee(&attendee, &0, &1);
+    assert_eq!(first.next_cursor, Some(1));
+    assert_eq!(ids(first), [e3]);
+    assert_eq!(ids(s.client.list_events_for_attendee(&attendee, &1, &1)), [e2]);
+}
@@ -0,0 +1,90 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use owner_rules::{OwnerRules, OwnerRulesClient as RulesClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, BytesN, Env, String, Val, Vec,
+};
+
+use crate::{PresenceEvents, PresenceEventsClient, Voucher};
+
+mod admin;
+mod certificates;
+mod checkin;
+mod enrollment;
+mod lifecycle;
+mod listings;
+mod pause;
+mod presence;
+mod series;
+mod vouchers;
+
+const NOW: u64 = 1_000_000;
+const HOUR: u64 = 3600;
+
+struct Setup {
+    env: Env,
+    client: PresenceEventsClient<'static>,
+    rules: RulesClient<'static>,
+    admin: Address,
+    supervisor: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+    let admin = Address::generate(&env);
+    let rules_id = env.register(OwnerRules, (admin.clone(),));
+    let rules = RulesClient::new(&env, &rules_id);
+    let id = env.register(PresenceEvents, (admin.clone(), rules_id));
+    let client = PresenceEventsClient::new(&env, &id);
+    let supervisor = Address::generate(&env);
+    mint_supervisor(&rules, &supervisor);
+    Setup { env, client, rules, admin, supervisor }
+}
+
+fn mint_supervisor(rules: &RulesClient, wallet: &Address) {
+    let token = rules.generate_supervisor_claim_link(wallet, &None);
+    rules.claim_nft(&token, wallet);
+}
+
+/// Creates an event open from `NOW` for one hour.
+fn open_event(s: &Setup) -> u64 {
+    s.client.create_event(&String::from_str(&s.env, "Assembleia"), &NOW, &(NOW + HOUR), &s.supervisor)
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|l| l.timestamp += secs);
+}
+
+/// Topics and data of the events the presence contract published during
+/// the last invocation.
+fn emitted(s: &Setup) -> std::vec::Vec<(Vec<Val>, Val)> {
+    s.env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, _, _)| *contract == s.client.address)
+        .map(|(_, topics, data)| (topics, data))
+        .collect()
+}
+
+fn sign(key: &SigningKey, msg: &Bytes) -> [u8; 64] {
+    let msg: std::vec::Vec<u8> = msg.iter().collect();
+    key.sign(&msg).to_bytes()
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn voucher(s: &Setup, event_id: u64, issued_at: u64, nonce: u64) -> Voucher {
+    Voucher { event_id, attendee: Address::generate(&s.env), supervisor: s.supervisor.clone(), issued_at, nonce }
+}
+
+fn sign_voucher(s: &Setup, key: &SigningKey, v: &Voucher) -> BytesN<64> {
+    let msg = s.client.voucher_message(&v.event_id, &v.attendee, &v.issued_at, &v.nonce);
+    BytesN::from_array(&s.env, &sign(key, &msg))
+}
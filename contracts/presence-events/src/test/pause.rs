@@ -0,0 +1,95 @@
+use soroban_sdk::{testutils::Address as _, Address, BytesN, String};
+
+use super::{advance, open_event, setup, Setup, HOUR, NOW};
+use crate::{CheckInVerifier, Error, PauseStatus};
+
+fn none() -> PauseStatus {
+    PauseStatus { claims: false, issuance: false, registration: false, event_creation: false, admin: false }
+}
+
+fn name(s: &Setup) -> String {
+    String::from_str(&s.env, "Assembleia")
+}
+
+fn verifier(s: &Setup) -> CheckInVerifier {
+    CheckInVerifier::SigningKey(BytesN::from_array(&s.env, &[1; 32]))
+}
+
+#[test]
+fn paused() {
+    let s = setup();
+    s.client.pause();
+    assert_eq!(s.client.try_create_event(&name(&s), &NOW, &(NOW + HOUR), &s.supervisor), Err(Ok(Error::Paused)));
+}
+
+#[test]
+fn event_creation_flag_blocks_event_management_only() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_pause(&PauseStatus { event_creation: true, ..none() });
+    assert_eq!(s.client.try_create_event(&name(&s), &NOW, &(NOW + HOUR), &s.supervisor), Err(Ok(Error::Paused)));
+    assert_eq!(s.client.try_cancel_event(&id, &s.supervisor), Err(Ok(Error::Paused)));
+    s.client.register_presence(&id, &s.supervisor, &Address::generate(&s.env));
+    s.client.open_checkin_session(&id, &s.supervisor, &verifier(&s));
+}
+
+#[test]
+fn registration_flag_blocks_presence_only() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    s.client.set_pause(&PauseStatus { registration: true, ..none() });
+    assert_eq!(
+        s.client.try_register_presence(&id, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(s.client.try_remove_presence(&id, &s.supervisor, &attendee), Err(Ok(Error::Paused)));
+    assert!(s.client.has_presence(&id, &attendee));
+    s.client.create_event(&name(&s), &NOW, &(NOW + HOUR), &s.supervisor);
+    s.client.cancel_event(&id, &s.supervisor);
+}
+
+#[test]
+fn issuance_flag_blocks_checkin_sessions_and_voucher_keys_only() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = BytesN::from_array(&s.env, &[2; 32]);
+    s.client.open_checkin_session(&id, &s.supervisor, &verifier(&s));
+    s.client.set_voucher_key(&s.supervisor, &key);
+    s.client.set_pause(&PauseStatus { issuance: true, ..none() });
+    assert_eq!(s.client.try_open_checkin_session(&id, &s.supervisor, &verifier(&s)), Err(Ok(Error::Paused)));
+    assert_eq!(s.client.try_close_checkin_session(&id, &s.supervisor), Err(Ok(Error::Paused)));
+    assert_eq!(s.client.try_set_voucher_key(&s.supervisor, &key), Err(Ok(Error::Paused)));
+    assert_eq!(s.client.try_remove_voucher_key(&s.supervisor), Err(Ok(Error::Paused)));
+    s.client.register_presence(&id, &s.supervisor, &Address::generate(&s.env));
+    s.client.create_event(&name(&s), &NOW, &(NOW + HOUR), &s.supervisor);
+}
+
+#[test]
+fn claims_flag_blocks_certificates_only() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    advance(&s.env, HOUR + 1);
+    s.client.set_pause(&PauseStatus { claims: true, ..none() });
+    assert_eq!(s.client.try_claim_certificate(&id, &attendee), Err(Ok(Error::Paused)));
+    s.client.create_event(&name(&s), &NOW, &(NOW + HOUR), &s.supervisor);
+    s.client.set_pause(&none());
+    s.client.claim_certificate(&id, &attendee);
+}
+
+#[test]
+fn admin_flag_blocks_handover_only() {
+    let s = setup();
+    let new_admin = Address::generate(&s.env);
+    s.client.propose_admin(&new_admin, &(NOW + HOUR));
+    s.client.set_pause(&PauseStatus { admin: true, ..none() });
+    assert_eq!(s.client.try_accept_admin(), Err(Ok(Error::Paused)));
+    assert_eq!(s.client.try_propose_admin(&new_admin, &(NOW + HOUR)), Err(Ok(Error::Paused)));
+    s.client.cancel_admin_proposal();
+    let id = open_event(&s);
+    s.client.register_presence(&id, &s.supervisor, &Address::generate(&s.env));
+    assert_eq!(s.client.get_admin(), s.admin);
+}
@@ -0,0 +1,223 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, IntoVal, String, Val, Vec};
+
+use super::{advance, emitted, mint_supervisor, open_event, setup, HOUR, NOW};
+use crate::contract::EVENT_VERSION;
+use crate::Error;
+
+#[test]
+fn too_early() {
+    let s = setup();
+    let name = String::from_str(&s.env, "Assembleia");
+    let id = s.client.create_event(&name, &(NOW + 24 * HOUR), &(NOW + 25 * HOUR), &s.supervisor);
+    assert_eq!(
+        s.client.try_register_presence(&id, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::TooEarly))
+    );
+}
+
+#[test]
+fn outside_window() {
+    let s = setup();
+    let id = open_event(&s);
+    advance(&s.env, HOUR + 1);
+    assert_eq!(
+        s.client.try_register_presence(&id, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::OutsideWindow))
+    );
+}
+
+#[test]
+fn default_checkin_window_applies_to_new_events() {
+    let s = setup();
+    s.client.set_default_checkin_window(&900, &300);
+    assert_eq!(s.client.get_default_checkin_window(), (900, 300));
+    let name = String::from_str(&s.env, "Oficina");
+    let on_time = s.client.create_event(&name, &(NOW + 900), &(NOW + 900 + HOUR), &s.supervisor);
+    let later = s.client.create_event(&name, &(NOW + 901), &(NOW + 901 + HOUR), &s.supervisor);
+    let event = s.client.get_event(&on_time).unwrap();
+    assert_eq!((event.early_checkin_secs, event.late_grace_secs), (900, 300));
+    s.client.register_presence(&on_time, &s.supervisor, &Address::generate(&s.env));
+    assert_eq!(
+        s.client.try_register_presence(&later, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::TooEarly))
+    );
+}
+
+#[test]
+fn late_grace_keeps_every_registration_path_open() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_checkin_window(&id, &s.supervisor, &0, &600);
+    advance(&s.env, HOUR + 600);
+    s.client.register_presence(&id, &s.supervisor, &Address::generate(&s.env));
+    let rejected = s.client.register_presence_batch(&id, &s.supervisor, &vec![&s.env, Address::generate(&s.env)]);
+    assert!(rejected.is_empty());
+    assert_eq!(s.client.active_attendees_count(&id), 2);
+    advance(&s.env, 1);
+    assert_eq!(
+        s.client.try_register_presence_batch(&id, &s.supervisor, &vec![&s.env, Address::generate(&s.env)]).err(),
+        Some(Ok(Error::OutsideWindow))
+    );
+}
+
+#[test]
+fn already_registered() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    assert_eq!(s.client.try_register_presence(&id, &s.supervisor, &attendee), Err(Ok(Error::AlreadyRegistered)));
+}
+
+#[test]
+fn attendee_not_registered() {
+    let s = setup();
+    let id = open_event(&s);
+    assert_eq!(
+        s.client.try_remove_presence(&id, &s.supervisor, &Address::generate(&s.env)),
+        Err(Ok(Error::AttendeeNotRegistered))
+    );
+}
+
+#[test]
+fn not_event_organizer() {
+    let s = setup();
+    let id = open_event(&s);
+    let other = Address::generate(&s.env);
+    mint_supervisor(&s.rules, &other);
+    assert_eq!(
+        s.client.try_register_presence(&id, &other, &Address::generate(&s.env)),
+        Err(Ok(Error::NotEventOrganizer))
+    );
+}
+
+#[test]
+fn already_checked_out() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    s.client.register_checkout(&id, &s.supervisor, &attendee);
+    assert_eq!(s.client.try_register_checkout(&id, &s.supervisor, &attendee), Err(Ok(Error::AlreadyCheckedOut)));
+}
+
+#[test]
+fn checkout_duration_is_clamped_to_the_event_window() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_checkin_window(&id, &s.supervisor, &0, &600);
+    let attendee = Address::generate(&s.env);
+    advance(&s.env, 60);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    assert_eq!(s.client.get_attendance_duration(&id, &attendee), None);
+    advance(&s.env, HOUR + 240);
+    s.client.register_checkout(&id, &s.supervisor, &attendee);
+    assert_eq!(s.client.get_attendance_duration(&id, &attendee), Some(HOUR - 60));
+    let row = s.client.list_attendees(&id, &0, &1, &true).items.get_unchecked(0);
+    assert_eq!(row.checked_out_at, Some(NOW + HOUR + 300));
+    assert_eq!(row.duration_secs, HOUR - 60);
+}
+
+#[test]
+fn minimum_duration_decides_who_counts_as_present() {
+    let s = setup();
+    let id = open_event(&s);
+    s.client.set_min_duration(&id, &s.supervisor, &(HOUR / 2));
+    let (early, stayed, absent) = (Address::generate(&s.env), Address::generate(&s.env), Address::generate(&s.env));
+    s.client.register_presence_batch(&id, &s.supervisor, &vec![&s.env, early.clone(), stayed.clone()]);
+    assert!(!s.client.is_present(&id, &early));
+    advance(&s.env, HOUR / 4);
+    s.client.register_checkout(&id, &s.supervisor, &early);
+    advance(&s.env, HOUR / 2);
+    s.client.register_checkout_batch(&id, &s.supervisor, &vec![&s.env, stayed.clone(), absent.clone()]);
+    assert!(!s.client.is_present(&id, &early));
+    assert!(s.client.is_present(&id, &stayed));
+    assert!(!s.client.is_present(&id, &absent));
+    assert_eq!(s.client.get_attendance_duration(&id, &stayed), Some(3 * HOUR / 4));
+}
+
+#[test]
+fn register_presence_emits_versioned_event() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("PresReg"), id, attendee).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32, u64, Address) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION, NOW, s.supervisor.clone()));
+}
+
+#[test]
+fn register_presence_batch_emits_one_event_per_attendee() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendees = vec![&s.env, Address::generate(&s.env), Address::generate(&s.env)];
+    s.client.register_presence_batch(&id, &s.supervisor, &attendees);
+    let events = emitted(&s);
+    assert_eq!(events.len(), 2);
+    for (i, (topics, data)) in events.into_iter().enumerate() {
+        let expected: Vec<Val> = (symbol_short!("PresReg"), id, attendees.get_unchecked(i as u32)).into_val(&s.env);
+        assert_eq!(topics, expected);
+        let data: (u32, u64, Address) = data.into_val(&s.env);
+        assert_eq!(data, (EVENT_VERSION, NOW, s.supervisor.clone()));
+    }
+}
+
+#[test]
+fn remove_presence_emits_versioned_event() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    s.client.remove_presence(&id, &s.supervisor, &attendee);
+    let events = emitted(&s);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events[0].clone();
+    let expected: Vec<Val> = (symbol_short!("PresRem"), id, attendee).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: (u32, Address) = data.into_val(&s.env);
+    assert_eq!(data, (EVENT_VERSION, s.supervisor.clone()));
+}
+
+#[test]
+fn removed_attendee_re_registers_in_the_same_row() {
+    let s = setup();
+    let id = open_event(&s);
+    let (a1, a2) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.register_presence(&id, &s.supervisor, &a1);
+    s.client.register_presence(&id, &s.supervisor, &a2);
+    s.client.remove_presence(&id, &s.supervisor, &a1);
+    assert!(!s.client.has_presence(&id, &a1));
+    assert_eq!(s.client.active_attendees_count(&id), 1);
+    let page = s.client.list_attendees(&id, &0, &10, &true);
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items.get_unchecked(0).address, a2);
+
+    advance(&s.env, 60);
+    s.client.register_presence(&id, &s.supervisor, &a1);
+    assert_eq!(s.client.active_attendees_count(&id), 2);
+    let page = s.client.list_attendees(&id, &0, &10, &false);
+    assert_eq!(page.items.len(), 2);
+    let row = page.items.get_unchecked(0);
+    assert_eq!(row.address, a1);
+    assert!(row.active);
+    assert_eq!(row.registered_at, NOW + 60);
+}
+
+#[test]
+fn remove_presence_twice_is_rejected() {
+    let s = setup();
+    let id = open_event(&s);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&id, &s.supervisor, &attendee);
+    s.client.remove_presence(&id, &s.supervisor, &attendee);
+    assert_eq!(
+        s.client.try_remove_presence(&id, &s.supervisor, &attendee),
+        Err(Ok(Error::AttendeeNotRegistered))
+    );
+    assert_eq!(s.client.active_attendees_count(&id), 0);
+}
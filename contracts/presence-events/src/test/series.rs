@@ -0,0 +1,69 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, String, Vec};
+
+use super::{advance, setup, HOUR, NOW};
+use crate::{Error, EventStatus, SessionWindow};
+
+const DAY: u64 = 24 * HOUR;
+
+#[test]
+fn series_not_found() {
+    let s = setup();
+    assert_eq!(s.client.try_list_series_sessions(&99).err(), Some(Ok(Error::SeriesNotFound)));
+}
+
+#[test]
+fn invalid_recurrence() {
+    let s = setup();
+    let sessions: Vec<SessionWindow> = Vec::new(&s.env);
+    assert_eq!(
+        s.client.try_create_series(&String::from_str(&s.env, "Curso"), &s.supervisor, &sessions),
+        Err(Ok(Error::InvalidRecurrence))
+    );
+}
+
+#[test]
+fn recurring_series_reports_attendance_across_sessions() {
+    let s = setup();
+    let name = String::from_str(&s.env, "Curso");
+    let series = s.client.create_recurring_series(&name, &s.supervisor, &NOW, &HOUR, &DAY, &3);
+    let sessions = s.client.get_series(&series).unwrap().sessions;
+    assert_eq!(sessions.len(), 3);
+    let attendee = Address::generate(&s.env);
+
+    s.client.register_presence(&sessions.get_unchecked(0), &s.supervisor, &attendee);
+    advance(&s.env, 2 * DAY);
+    s.client.register_presence(&sessions.get_unchecked(2), &s.supervisor, &attendee);
+    let ratio = s.client.get_series_attendance(&series, &attendee);
+    assert_eq!((ratio.attended, ratio.held, ratio.total), (2, 2, 3));
+
+    advance(&s.env, HOUR + 1);
+    let ratio = s.client.get_series_attendance(&series, &attendee);
+    assert_eq!((ratio.attended, ratio.held, ratio.total), (2, 3, 3));
+}
+
+#[test]
+fn cancelled_sessions_leave_the_series_totals() {
+    let s = setup();
+    let windows = vec![
+        &s.env,
+        SessionWindow { start_ts: NOW, end_ts: NOW + HOUR },
+        SessionWindow { start_ts: NOW + DAY, end_ts: NOW + DAY + HOUR },
+    ];
+    let series = s.client.create_series(&String::from_str(&s.env, "Curso"), &s.supervisor, &windows);
+    let sessions = s.client.list_series_sessions(&series);
+    s.client.cancel_event(&sessions.get_unchecked(1).id, &s.supervisor);
+    let attendee = Address::generate(&s.env);
+    s.client.register_presence(&sessions.get_unchecked(0).id, &s.supervisor, &attendee);
+    assert_eq!(s.client.list_series_sessions(&series).get_unchecked(1).status, EventStatus::Cancelled);
+    let ratio = s.client.get_series_attendance(&series, &attendee);
+    assert_eq!((ratio.attended, ratio.held, ratio.total), (1, 0, 1));
+}
+
+#[test]
+fn recurring_sessions_must_not_overlap() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_create_recurring_series(&String::from_str(&s.env, "Curso"), &s.supervisor, &NOW, &HOUR, &(HOUR - 1), &3),
+        Err(Ok(Error::InvalidRecurrence))
+    );
+}
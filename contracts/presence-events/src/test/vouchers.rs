@@ -0,0 +1,80 @@
+use ed25519_dalek::SigningKey;
+use soroban_sdk::{BytesN, InvokeError};
+
+use super::{advance, open_event, public_key, setup, sign_voucher, voucher, HOUR, NOW};
+use crate::Error;
+
+#[test]
+fn voucher_key_not_set() {
+    let s = setup();
+    assert_eq!(s.client.try_remove_voucher_key(&s.supervisor), Err(Ok(Error::VoucherKeyNotSet)));
+}
+
+#[test]
+fn voucher_already_used() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[5; 32]);
+    s.client.set_voucher_key(&s.supervisor, &public_key(&s.env, &key));
+    let v = voucher(&s, id, NOW, 1);
+    let signature = sign_voucher(&s, &key, &v);
+    s.client.redeem_voucher(&v, &signature);
+    assert_eq!(s.client.try_redeem_voucher(&v, &signature), Err(Ok(Error::VoucherAlreadyUsed)));
+}
+
+#[test]
+fn invalid_voucher() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[5; 32]);
+    s.client.set_voucher_key(&s.supervisor, &public_key(&s.env, &key));
+    let v = voucher(&s, id, NOW + 60, 1);
+    let signature = sign_voucher(&s, &key, &v);
+    assert_eq!(s.client.try_redeem_voucher(&v, &signature), Err(Ok(Error::InvalidVoucher)));
+    let forged = BytesN::from_array(&s.env, &[0; 64]);
+    assert_eq!(s.client.try_redeem_voucher(&v, &forged), Err(Ok(Error::InvalidVoucher)));
+}
+
+#[test]
+fn redeem_voucher_registers_attendee_as_of_issue_time() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[5; 32]);
+    s.client.set_voucher_key(&s.supervisor, &public_key(&s.env, &key));
+    let v = voucher(&s, id, NOW + 60, 1);
+    let signature = sign_voucher(&s, &key, &v);
+    advance(&s.env, HOUR);
+    s.client.redeem_voucher(&v, &signature);
+    let info = s.client.list_attendees(&id, &0, &10, &true).items.get_unchecked(0);
+    assert_eq!((info.address, info.registered_at), (v.attendee, NOW + 60));
+}
+
+#[test]
+fn voucher_nonce_is_single_use_per_supervisor() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[5; 32]);
+    s.client.set_voucher_key(&s.supervisor, &public_key(&s.env, &key));
+    let first = voucher(&s, id, NOW, 7);
+    s.client.redeem_voucher(&first, &sign_voucher(&s, &key, &first));
+    let second = voucher(&s, id, NOW, 7);
+    assert_eq!(
+        s.client.try_redeem_voucher(&second, &sign_voucher(&s, &key, &second)),
+        Err(Ok(Error::VoucherAlreadyUsed))
+    );
+    assert!(!s.client.has_presence(&id, &second.attendee));
+}
+
+#[test]
+fn forged_voucher_aborts_without_registering() {
+    let s = setup();
+    let id = open_event(&s);
+    let key = SigningKey::from_bytes(&[5; 32]);
+    s.client.set_voucher_key(&s.supervisor, &public_key(&s.env, &key));
+    let v = voucher(&s, id, NOW, 1);
+    let forged = sign_voucher(&s, &SigningKey::from_bytes(&[9; 32]), &v);
+    assert_eq!(s.client.try_redeem_voucher(&v, &forged), Err(Err(InvokeError::Abort)));
+    assert!(!s.client.has_presence(&id, &v.attendee));
+    s.client.redeem_voucher(&v, &sign_voucher(&s, &key, &v));
+    assert!(s.client.has_presence(&id, &v.attendee));
+}
@@ -37,21 +37,128 @@ export const networks = {
   },
 } as const;
 
+export const Errors = {
+  1: { message: "AlreadyInitialized" },
+  2: { message: "AdminNotSet" },
+  3: { message: "Paused" },
+  4: { message: "Reentrancy" },
+  5: { message: "InvalidClaimToken" },
+  6: { message: "TokenAlreadyUsed" },
+  7: { message: "RecipientMismatch" },
+  8: { message: "AlreadyHasRole" },
+  9: { message: "CredentialNotFound" },
+  10: { message: "NotAuthorized" },
+  11: { message: "NotAllowed" },
+  12: { message: "ClaimRevoked" },
+  13: { message: "ClaimExpired" },
+  14: { message: "InvalidExpiry" },
+  15: { message: "SecretRequired" },
+  16: { message: "InvalidMaxUses" },
+  17: { message: "DuplicateClaim" },
+  18: { message: "QuotaExceeded" },
+  19: { message: "NotGuardian" },
+  20: { message: "InvalidThreshold" },
+  21: { message: "InvalidGuardians" },
+  22: { message: "RecoveryPending" },
+  23: { message: "NoRecoveryPending" },
+  24: { message: "AlreadyApproved" },
+  25: { message: "ApprovalWindowClosed" },
+  26: { message: "TimelockActive" },
+  27: { message: "NoPendingAdmin" },
+  28: { message: "AdminProposalExpired" },
+  29: { message: "CouncilRequired" },
+  30: { message: "NotCouncilMember" },
+  31: { message: "InvalidCouncil" },
+  32: { message: "ProposalNotFound" },
+  33: { message: "ProposalExpired" },
+  34: { message: "ProposalExecuted" },
+  35: { message: "ThresholdNotMet" },
+};
+
 export type DataKey =
   | { tag: "RoleByWallet"; values: readonly [string] }
   | { tag: "TokenById"; values: readonly [u64] }
   | { tag: "ClaimToken"; values: readonly [Buffer] }
-  | { tag: "Authorized"; values: readonly [string] };
+  | { tag: "IssuanceQuota"; values: readonly [string] }
+  | { tag: "IssuedCount"; values: readonly [string] }
+  | { tag: "CouncilProposal"; values: readonly [u64] };
+
+/**
+ * Emergency-stop flags. presence-events has its own flags and storage;
+ * each contract is paused separately. Each flag halts a group of
+ * entrypoints; see `set_pause`.
+ */
+export interface PauseStatus {
+  admin: boolean;
+  claims: boolean;
+  issuance: boolean;
+}
+
+/**
+ * Privileged operations the admin council decides on.
+ */
+export type AdminAction =
+  | { tag: "Pause"; values: void }
+  | { tag: "Unpause"; values: void }
+  | { tag: "SetPause"; values: readonly [PauseStatus] }
+  | { tag: "RevokeCredential"; values: readonly [string, u32] }
+  | { tag: "SetEventContract"; values: readonly [string] }
+  | { tag: "SetCouncil"; values: readonly [Array<string>, u32] }
+  | { tag: "DissolveCouncil"; values: void };
+
+export interface CouncilProposal {
+  action: AdminAction;
+  approvals: Array<string>;
+  executed: boolean;
+  expires_at: u64;
+  id: u64;
+  proposer: string;
+}
+
+/**
+ * Admin handover awaiting acceptance by `new_admin`.
+ */
+export interface PendingAdmin {
+  expires_at: u64;
+  new_admin: string;
+}
+
+/**
+ * A guardian-initiated admin recovery. Becomes executable once
+ * `approvals` reaches the threshold, after the recovery timelock.
+ */
+export interface RecoveryProposal {
+  approvals: Array<string>;
+  approve_by: u64;
+  created_at: u64;
+  executable_at: Option<u64>;
+  new_admin: string;
+  proposer: string;
+}
 
 export interface TokenData {
   owner: string;
   role_id: u32;
 }
 
+export enum ClaimStatus {
+  Valid = 0,
+  Used = 1,
+  Revoked = 2,
+  Expired = 3,
+}
+
 export interface ClaimData {
-  recipient: string;
+  expires_at: u64;
+  issuer: string;
+  max_uses: u32;
+  /**
+   * `None` for open links, redeemable by whoever presents the secret.
+   */
+  recipient: Option<string>;
   role_id: u32;
-  valid: boolean;
+  status: ClaimStatus;
+  uses: u32;
 }
 
 export interface Client {
@@ -76,13 +183,16 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * First step of an admin handover. Nothing changes until `new_admin`
+   * calls `accept_admin` before `expires_at`. Blocked by the `admin`
+   * pause flag.
    */
-  transfer_admin: (
-    { new_admin }: { new_admin: string },
+  propose_admin: (
+    { new_admin, expires_at }: { new_admin: string; expires_at: u64 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -99,10 +209,94 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Completes the handover, moving the `ROLE_ADMIN` credential as well.
+   */
+  accept_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a cancel_admin_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_admin_proposal: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the outstanding proposal, if any. An expired proposal stays
+   * visible until it is cancelled or replaced.
+   */
+  get_pending_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PendingAdmin>>>;
 
   /**
    * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Raises every pause flag.
    */
   pause: (options?: {
     /**
@@ -119,7 +313,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+  }) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
    * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -139,7 +333,58 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets each pause flag individually:
+   * - `claims`: `claim_nft` and `claim_nft_with_secret`.
+   * - `issuance`: claim link generation and credential revocation,
+   * including revocations run by the council.
+   * - `admin`: `propose_admin` and `accept_admin`.
+   *
+   * Cancelling an admin proposal, guardian recovery and the other council
+   * actions stay available so a paused contract can still be recovered.
+   */
+  set_pause: (
+    { status }: { status: PauseStatus },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a pause_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pause_status: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PauseStatus>>;
 
   /**
    * Construct and simulate a set_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -162,7 +407,7 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
    * Construct and simulate a set_event_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -185,7 +430,7 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
    * Construct and simulate a get_event_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -208,10 +453,62 @@ export interface Client {
   }) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
-   * Construct and simulate a authorize_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_guardians transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replaces the guardian set. Any pending recovery is discarded since
+   * its approvals came from the previous set. An empty set with a zero
+   * threshold disables recovery.
+   */
+  set_guardians: (
+    { guardians, threshold }: { guardians: Array<string>; threshold: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_guardians transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the guardian set and the number of approvals a recovery needs.
+   */
+  get_guardians: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [Array<string>, u32]>>;
+
+  /**
+   * Construct and simulate a set_recovery_timing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets how long guardians have to reach the threshold and how long the
+   * admin then has to veto before the recovery can be executed.
    */
-  authorize_contract: (
-    { contract, status }: { contract: string; status: boolean },
+  set_recovery_timing: (
+    {
+      approval_window_secs,
+      timelock_secs,
+    }: { approval_window_secs: u64; timelock_secs: u64 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -228,13 +525,36 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_recovery_timing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns `(approval_window_secs, timelock_secs)`.
+   */
+  get_recovery_timing: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [u64, u64]>>;
 
   /**
-   * Construct and simulate a recover_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a propose_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Opens a recovery proposal naming `new_admin`. The proposer's approval
+   * is counted. Replaces a previous proposal only if it lapsed unapproved.
    */
-  recover_admin: (
-    { new_admin, operator }: { new_admin: string; operator: string },
+  propose_recovery: (
+    { guardian, new_admin }: { guardian: string; new_admin: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -251,13 +571,13 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a generate_supervisor_claim_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a approve_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  generate_supervisor_claim_link: (
-    { recipient }: { recipient: string },
+  approve_recovery: (
+    { guardian }: { guardian: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -274,13 +594,78 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Buffer>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a generate_associate_claim_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a veto_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lets the current admin discard a pending recovery, at any point
+   * before it is executed.
    */
-  generate_associate_claim_link: (
-    { recipient, operator }: { recipient: string; operator: string },
+  veto_recovery: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a execute_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hands the admin role to the proposed address once the proposal is
+   * approved and its timelock has passed. Callable by anyone.
+   */
+  execute_recovery: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_recovery_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_recovery_proposal: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<RecoveryProposal>>>;
+
+  /**
+   * Construct and simulate a set_default_claim_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lifetime of claim links generated without an explicit `expires_at`.
+   */
+  set_default_claim_ttl: (
+    { ttl_secs }: { ttl_secs: u64 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -297,13 +682,35 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Buffer>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a claim_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_default_claim_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  claim_nft: (
-    { token_hash, wallet }: { token_hash: Buffer; wallet: string },
+  get_default_claim_ttl: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a set_default_issuance_quota transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets how many associate claims supervisors may issue in total.
+   * Applies to every supervisor without an individual quota.
+   */
+  set_default_issuance_quota: (
+    { quota }: { quota: u32 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -320,13 +727,467 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<u64>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a revoke_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_default_issuance_quota transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  revoke_credential: (
-    { wallet, role_id }: { wallet: string; role_id: u32 },
+  get_default_issuance_quota: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_issuance_quota transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_issuance_quota: (
+    { supervisor, quota }: { supervisor: string; quota: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_issuance_quota transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns `(quota, issued)` for `supervisor`.
+   */
+  get_issuance_quota: (
+    { supervisor }: { supervisor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<readonly [u32, u32]>>;
+
+  /**
+   * Construct and simulate a generate_supervisor_claim_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  generate_supervisor_claim_link: (
+    { recipient, expires_at }: { recipient: string; expires_at: Option<u64> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Buffer>>>;
+
+  /**
+   * Construct and simulate a generate_associate_claim_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Issues an associate invitation. `operator` must be the admin or a
+   * supervisor; supervisors draw from their issuance quota.
+   */
+  generate_associate_claim_link: (
+    {
+      recipient,
+      operator,
+      expires_at,
+    }: { recipient: string; operator: string; expires_at: Option<u64> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Buffer>>>;
+
+  /**
+   * Construct and simulate a generate_open_associate_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Registers an open associate invitation. `secret_hash` is the sha256
+   * of a random secret carried by the link; every use counts against the
+   * operator's quota. The secret is public once first redeemed, so open
+   * links only grant the associate role; supervisors are invited with
+   * recipient-bound links.
+   */
+  generate_open_associate_link: (
+    {
+      secret_hash,
+      operator,
+      max_uses,
+      expires_at,
+    }: {
+      secret_hash: Buffer;
+      operator: string;
+      max_uses: u32;
+      expires_at: Option<u64>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a revoke_claim_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revokes an unused claim link. Callable by the admin or the issuer.
+   */
+  revoke_claim_link: (
+    { token_hash, operator }: { token_hash: Buffer; operator: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a expire_claim_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Marks a claim link past its expiry as expired and emits `ClaimExp`.
+   * Callable by anyone; `claim_nft` rejects expired links on its own, but
+   * events of a failed call are not recorded.
+   */
+  expire_claim_link: (
+    { token_hash }: { token_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_claim_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_claim_status: (
+    { token_hash }: { token_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<ClaimStatus>>>;
+
+  /**
+   * Construct and simulate a claim_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeems a recipient-bound claim link. The recipient must authorize,
+   * so the link can't be redeemed on their behalf by whoever holds it.
+   */
+  claim_nft: (
+    { token_hash, wallet }: { token_hash: Buffer; wallet: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a claim_nft_with_secret transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeems an open claim link by presenting the secret whose sha256 was
+   * registered. The first wallets to present it (up to `max_uses`) win;
+   * after the first redemption the secret is visible on chain.
+   */
+  claim_nft_with_secret: (
+    { secret, wallet }: { secret: Buffer; wallet: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a revoke_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  revoke_credential: (
+    { wallet, role_id }: { wallet: string; role_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_council transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Switches `pause`, `unpause`, `set_pause`, `revoke_credential` and
+   * `set_event_contract` to council mode: afterwards they only run
+   * through approved proposals. Every member receives `ROLE_ADMIN`.
+   */
+  set_council: (
+    { members, threshold }: { members: Array<string>; threshold: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_council transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the council members and the approvals a proposal needs.
+   * An empty set means the single admin acts alone.
+   */
+  get_council: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [Array<string>, u32]>>;
+
+  /**
+   * Construct and simulate a propose_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Opens a proposal for a privileged action. The proposer's approval is
+   * counted. Returns the proposal id.
+   */
+  propose_action: (
+    {
+      member,
+      action,
+      expires_at,
+    }: { member: string; action: AdminAction; expires_at: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a approve_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve_action: (
+    { member, proposal_id }: { member: string; proposal_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a execute_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Runs an approved proposal. Only approvals from current members count.
+   * Callable by anyone.
+   */
+  execute_action: (
+    { proposal_id }: { proposal_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_action: (
+    { proposal_id }: { proposal_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<CouncilProposal>>>;
+
+  /**
+   * Construct and simulate a bump_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extends the TTL of `wallet`'s role map and credential tokens so
+   * archivers can keep them alive. Callable by anyone.
+   */
+  bump_credential: (
+    { wallet }: { wallet: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -343,7 +1204,7 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
    * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -434,23 +1295,61 @@ export class Client extends ContractClient {
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAADFJvbGVCeVdhbGxldAAAAAEAAAATAAAAAQAAAAAAAAAJVG9rZW5CeUlkAAAAAAAAAQAAAAYAAAABAAAAAAAAAApDbGFpbVRva2VuAAAAAAABAAAD7gAAACAAAAABAAAAAAAAAApBdXRob3JpemVkAAAAAAABAAAAEw==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAIwAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAABAAAAAAAAAAtBZG1pbk5vdFNldAAAAAACAAAAAAAAAAZQYXVzZWQAAAAAAAMAAAAAAAAAClJlZW50cmFuY3kAAAAAAAQAAAAAAAAAEUludmFsaWRDbGFpbVRva2VuAAAAAAAABQAAAAAAAAAQVG9rZW5BbHJlYWR5VXNlZAAAAAYAAAAAAAAAEVJlY2lwaWVudE1pc21hdGNoAAAAAAAABwAAAAAAAAAOQWxyZWFkeUhhc1JvbGUAAAAAAAgAAAAAAAAAEkNyZWRlbnRpYWxOb3RGb3VuZAAAAAAACQAAAIZEZXByZWNhdGVkOiBubyBlbnRyeXBvaW50IHJldHVybnMgdGhpcyBjb2RlIGFueSBtb3JlLiBUaGUgc2xvdCBzdGF5cwpyZXNlcnZlZCBzbyBjbGllbnRzIGRlY29kaW5nIG9sZGVyIHRyYW5zYWN0aW9ucyBrZWVwIGl0cyBtZWFuaW5nLgAAAAAADU5vdEF1dGhvcml6ZWQAAAAAAAAKAAAAAAAAAApOb3RBbGxvd2VkAAAAAAALAAAAAAAAAAxDbGFpbVJldm9rZWQAAAAMAAAAAAAAAAxDbGFpbUV4cGlyZWQAAAANAAAAAAAAAA1JbnZhbGlkRXhwaXJ5AAAAAAAADgAAAAAAAAAOU2VjcmV0UmVxdWlyZWQAAAAAAA8AAAAAAAAADkludmFsaWRNYXhVc2VzAAAAAAAQAAAAAAAAAA5EdXBsaWNhdGVDbGFpbQAAAAAAEQAAAAAAAAANUXVvdGFFeGNlZWRlZAAAAAAAABIAAAAAAAAAC05vdEd1YXJkaWFuAAAAABMAAAAAAAAAEEludmFsaWRUaHJlc2hvbGQAAAAUAAAAAAAAABBJbnZhbGlkR3VhcmRpYW5zAAAAFQAAAAAAAAAPUmVjb3ZlcnlQZW5kaW5nAAAAABYAAAAAAAAAEU5vUmVjb3ZlcnlQZW5kaW5nAAAAAAAAFwAAAAAAAAAPQWxyZWFkeUFwcHJvdmVkAAAAABgAAAAAAAAAFEFwcHJvdmFsV2luZG93Q2xvc2VkAAAAGQAAAAAAAAAOVGltZWxvY2tBY3RpdmUAAAAAABoAAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAAAbAAAAAAAAABRBZG1pblByb3Bvc2FsRXhwaXJlZAAAABwAAAAAAAAAD0NvdW5jaWxSZXF1aXJlZAAAAAAdAAAAAAAAABBOb3RDb3VuY2lsTWVtYmVyAAAAHgAAAAAAAAAOSW52YWxpZENvdW5jaWwAAAAAAB8AAAAAAAAAEFByb3Bvc2FsTm90Rm91bmQAAAAgAAAAAAAAAA9Qcm9wb3NhbEV4cGlyZWQAAAAAIQAAAAAAAAAQUHJvcG9zYWxFeGVjdXRlZAAAACIAAAAAAAAAD1RocmVzaG9sZE5vdE1ldAAAAAAj",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABgAAAAEAAAAAAAAADFJvbGVCeVdhbGxldAAAAAEAAAATAAAAAQAAAAAAAAAJVG9rZW5CeUlkAAAAAAAAAQAAAAYAAAABAAAAAAAAAApDbGFpbVRva2VuAAAAAAABAAAD7gAAACAAAAABAAAAAAAAAA1Jc3N1YW5jZVF1b3RhAAAAAAAAAQAAABMAAAABAAAAAAAAAAtJc3N1ZWRDb3VudAAAAAABAAAAEwAAAAEAAAAAAAAAD0NvdW5jaWxQcm9wb3NhbAAAAAABAAAABg==",
+        "AAAAAQAAAKFFbWVyZ2VuY3ktc3RvcCBmbGFncy4gcHJlc2VuY2UtZXZlbnRzIGhhcyBpdHMgb3duIGZsYWdzIGFuZCBzdG9yYWdlOwplYWNoIGNvbnRyYWN0IGlzIHBhdXNlZCBzZXBhcmF0ZWx5LiBFYWNoIGZsYWcgaGFsdHMgYSBncm91cCBvZgplbnRyeXBvaW50czsgc2VlIGBzZXRfcGF1c2VgLgAAAAAAAAAAAAALUGF1c2VTdGF0dXMAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAABAAAAAAAAAAZjbGFpbXMAAAAAAAEAAAAAAAAACGlzc3VhbmNlAAAAAQ==",
+        "AAAAAgAAADNQcml2aWxlZ2VkIG9wZXJhdGlvbnMgdGhlIGFkbWluIGNvdW5jaWwgZGVjaWRlcyBvbi4AAAAAAAAAAAtBZG1pbkFjdGlvbgAAAAAHAAAAAAAAAAAAAAAFUGF1c2UAAAAAAAAAAAAAAAAAAAdVbnBhdXNlAAAAAAEAAAAAAAAACFNldFBhdXNlAAAAAQAAB9AAAAALUGF1c2VTdGF0dXMAAAAAAQAAAAAAAAAQUmV2b2tlQ3JlZGVudGlhbAAAAAIAAAATAAAABAAAAAEAAAAAAAAAEFNldEV2ZW50Q29udHJhY3QAAAABAAAAEAAAAAEAAAAAAAAAClNldENvdW5jaWwAAAAAAAIAAAPqAAAAEwAAAAQAAAAAAAAAAAAAAA9EaXNzb2x2ZUNvdW5jaWwA",
+        "AAAAAQAAAAAAAAAAAAAAD0NvdW5jaWxQcm9wb3NhbAAAAAAGAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAALQWRtaW5BY3Rpb24AAAAAAAAAAAlhcHByb3ZhbHMAAAAAAAPqAAAAEwAAAAAAAAAIZXhlY3V0ZWQAAAABAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAIcHJvcG9zZXIAAAAT",
+        "AAAAAQAAADJBZG1pbiBoYW5kb3ZlciBhd2FpdGluZyBhY2NlcHRhbmNlIGJ5IGBuZXdfYWRtaW5gLgAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAACAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAluZXdfYWRtaW4AAAAAAAAT",
+        "AAAAAQAAAHxBIGd1YXJkaWFuLWluaXRpYXRlZCBhZG1pbiByZWNvdmVyeS4gQmVjb21lcyBleGVjdXRhYmxlIG9uY2UKYGFwcHJvdmFsc2AgcmVhY2hlcyB0aGUgdGhyZXNob2xkLCBhZnRlciB0aGUgcmVjb3ZlcnkgdGltZWxvY2suAAAAAAAAABBSZWNvdmVyeVByb3Bvc2FsAAAABgAAAAAAAAAJYXBwcm92YWxzAAAAAAAD6gAAABMAAAAAAAAACmFwcHJvdmVfYnkAAAAAAAYAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADWV4ZWN1dGFibGVfYXQAAAAAAAPoAAAABgAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAAAAAAIcHJvcG9zZXIAAAAT",
         "AAAAAQAAAAAAAAAAAAAACVRva2VuRGF0YQAAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAHcm9sZV9pZAAAAAAE",
-        "AAAAAQAAAAAAAAAAAAAACUNsYWltRGF0YQAAAAAAAAMAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAAB3JvbGVfaWQAAAAABAAAAAAAAAAFdmFsaWQAAAAAAAAB",
-        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAA==",
+        "AAAAAwAAAAAAAAAAAAAAC0NsYWltU3RhdHVzAAAAAAQAAAAAAAAABVZhbGlkAAAAAAAAAAAAAAAAAAAEVXNlZAAAAAEAAAAAAAAAB1Jldm9rZWQAAAAAAgAAAAAAAAAHRXhwaXJlZAAAAAAD",
+        "AAAAAQAAAAAAAAAAAAAACUNsYWltRGF0YQAAAAAAAAcAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAABmlzc3VlcgAAAAAAEwAAAAAAAAAIbWF4X3VzZXMAAAAEAAAAQWBOb25lYCBmb3Igb3BlbiBsaW5rcywgcmVkZWVtYWJsZSBieSB3aG9ldmVyIHByZXNlbnRzIHRoZSBzZWNyZXQuAAAAAAAACXJlY2lwaWVudAAAAAAAA+gAAAATAAAAAAAAAAdyb2xlX2lkAAAAAAQAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtDbGFpbVN0YXR1cwAAAAAAAAAABHVzZXMAAAAE",
+        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAOdHJhbnNmZXJfYWRtaW4AAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAAAAAAAAA==",
-        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAAAAAAAAA==",
-        "AAAAAAAAAAAAAAAMc2V0X2Jhc2VfdXJpAAAAAQAAAAAAAAAHbmV3X3VyaQAAAAAQAAAAAA==",
-        "AAAAAAAAAAAAAAASc2V0X2V2ZW50X2NvbnRyYWN0AAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAQAAAAAA==",
+        "AAAAAAAAAI9GaXJzdCBzdGVwIG9mIGFuIGFkbWluIGhhbmRvdmVyLiBOb3RoaW5nIGNoYW5nZXMgdW50aWwgYG5ld19hZG1pbmAKY2FsbHMgYGFjY2VwdF9hZG1pbmAgYmVmb3JlIGBleHBpcmVzX2F0YC4gQmxvY2tlZCBieSB0aGUgYGFkbWluYApwYXVzZSBmbGFnLgAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAIAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAENDb21wbGV0ZXMgdGhlIGhhbmRvdmVyLCBtb3ZpbmcgdGhlIGBST0xFX0FETUlOYCBjcmVkZW50aWFsIGFzIHdlbGwuAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAVY2FuY2VsX2FkbWluX3Byb3Bvc2FsAAAAAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAG5SZXR1cm5zIHRoZSBvdXRzdGFuZGluZyBwcm9wb3NhbCwgaWYgYW55LiBBbiBleHBpcmVkIHByb3Bvc2FsIHN0YXlzCnZpc2libGUgdW50aWwgaXQgaXMgY2FuY2VsbGVkIG9yIHJlcGxhY2VkLgAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAH0AAAAAxQZW5kaW5nQWRtaW4=",
+        "AAAAAAAAABhSYWlzZXMgZXZlcnkgcGF1c2UgZmxhZy4AAAAFcGF1c2UAAAAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAXpTZXRzIGVhY2ggcGF1c2UgZmxhZyBpbmRpdmlkdWFsbHk6Ci0gYGNsYWltc2A6IGBjbGFpbV9uZnRgIGFuZCBgY2xhaW1fbmZ0X3dpdGhfc2VjcmV0YC4KLSBgaXNzdWFuY2VgOiBjbGFpbSBsaW5rIGdlbmVyYXRpb24gYW5kIGNyZWRlbnRpYWwgcmV2b2NhdGlvbiwKaW5jbHVkaW5nIHJldm9jYXRpb25zIHJ1biBieSB0aGUgY291bmNpbC4KLSBgYWRtaW5gOiBgcHJvcG9zZV9hZG1pbmAgYW5kIGBhY2NlcHRfYWRtaW5gLgoKQ2FuY2VsbGluZyBhbiBhZG1pbiBwcm9wb3NhbCwgZ3VhcmRpYW4gcmVjb3ZlcnkgYW5kIHRoZSBvdGhlciBjb3VuY2lsCmFjdGlvbnMgc3RheSBhdmFpbGFibGUgc28gYSBwYXVzZWQgY29udHJhY3QgY2FuIHN0aWxsIGJlIHJlY292ZXJlZC4AAAAAAAlzZXRfcGF1c2UAAAAAAAABAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAALUGF1c2VTdGF0dXMAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAMcGF1c2Vfc3RhdHVzAAAAAAAAAAEAAAfQAAAAC1BhdXNlU3RhdHVzAA==",
+        "AAAAAAAAAAAAAAAMc2V0X2Jhc2VfdXJpAAAAAQAAAAAAAAAHbmV3X3VyaQAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAASc2V0X2V2ZW50X2NvbnRyYWN0AAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAAAAAAAASZ2V0X2V2ZW50X2NvbnRyYWN0AAAAAAAAAAAAAQAAA+gAAAAQ",
-        "AAAAAAAAAAAAAAASYXV0aG9yaXplX2NvbnRyYWN0AAAAAAACAAAAAAAAAAhjb250cmFjdAAAABMAAAAAAAAABnN0YXR1cwAAAAAAAQAAAAA=",
-        "AAAAAAAAAAAAAAANcmVjb3Zlcl9hZG1pbgAAAAAAAAIAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAeZ2VuZXJhdGVfc3VwZXJ2aXNvcl9jbGFpbV9saW5rAAAAAAABAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAQAAA+4AAAAg",
-        "AAAAAAAAAAAAAAAdZ2VuZXJhdGVfYXNzb2NpYXRlX2NsYWltX2xpbmsAAAAAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAD7gAAACA=",
-        "AAAAAAAAAAAAAAAJY2xhaW1fbmZ0AAAAAAAAAgAAAAAAAAAKdG9rZW5faGFzaAAAAAAD7gAAACAAAAAAAAAABndhbGxldAAAAAAAEwAAAAEAAAAG",
-        "AAAAAAAAAAAAAAARcmV2b2tlX2NyZWRlbnRpYWwAAAAAAAACAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAAAAAAAB3JvbGVfaWQAAAAABAAAAAA=",
+        "AAAAAAAAAKJSZXBsYWNlcyB0aGUgZ3VhcmRpYW4gc2V0LiBBbnkgcGVuZGluZyByZWNvdmVyeSBpcyBkaXNjYXJkZWQgc2luY2UKaXRzIGFwcHJvdmFscyBjYW1lIGZyb20gdGhlIHByZXZpb3VzIHNldC4gQW4gZW1wdHkgc2V0IHdpdGggYSB6ZXJvCnRocmVzaG9sZCBkaXNhYmxlcyByZWNvdmVyeS4AAAAAAA1zZXRfZ3VhcmRpYW5zAAAAAAAAAgAAAAAAAAAJZ3VhcmRpYW5zAAAAAAAD6gAAABMAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEZSZXR1cm5zIHRoZSBndWFyZGlhbiBzZXQgYW5kIHRoZSBudW1iZXIgb2YgYXBwcm92YWxzIGEgcmVjb3ZlcnkgbmVlZHMuAAAAAAANZ2V0X2d1YXJkaWFucwAAAAAAAAAAAAABAAAD7QAAAAIAAAPqAAAAEwAAAAQ=",
+        "AAAAAAAAAIBTZXRzIGhvdyBsb25nIGd1YXJkaWFucyBoYXZlIHRvIHJlYWNoIHRoZSB0aHJlc2hvbGQgYW5kIGhvdyBsb25nIHRoZQphZG1pbiB0aGVuIGhhcyB0byB2ZXRvIGJlZm9yZSB0aGUgcmVjb3ZlcnkgY2FuIGJlIGV4ZWN1dGVkLgAAABNzZXRfcmVjb3ZlcnlfdGltaW5nAAAAAAIAAAAAAAAAFGFwcHJvdmFsX3dpbmRvd19zZWNzAAAABgAAAAAAAAANdGltZWxvY2tfc2VjcwAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADBSZXR1cm5zIGAoYXBwcm92YWxfd2luZG93X3NlY3MsIHRpbWVsb2NrX3NlY3MpYC4AAAATZ2V0X3JlY292ZXJ5X3RpbWluZwAAAAAAAAAAAQAAA+0AAAACAAAABgAAAAY=",
+        "AAAAAAAAAIxPcGVucyBhIHJlY292ZXJ5IHByb3Bvc2FsIG5hbWluZyBgbmV3X2FkbWluYC4gVGhlIHByb3Bvc2VyJ3MgYXBwcm92YWwKaXMgY291bnRlZC4gUmVwbGFjZXMgYSBwcmV2aW91cyBwcm9wb3NhbCBvbmx5IGlmIGl0IGxhcHNlZCB1bmFwcHJvdmVkLgAAABBwcm9wb3NlX3JlY292ZXJ5AAAAAgAAAAAAAAAIZ3VhcmRpYW4AAAATAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAQYXBwcm92ZV9yZWNvdmVyeQAAAAEAAAAAAAAACGd1YXJkaWFuAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAFZMZXRzIHRoZSBjdXJyZW50IGFkbWluIGRpc2NhcmQgYSBwZW5kaW5nIHJlY292ZXJ5LCBhdCBhbnkgcG9pbnQKYmVmb3JlIGl0IGlzIGV4ZWN1dGVkLgAAAAAADXZldG9fcmVjb3ZlcnkAAAAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAHtIYW5kcyB0aGUgYWRtaW4gcm9sZSB0byB0aGUgcHJvcG9zZWQgYWRkcmVzcyBvbmNlIHRoZSBwcm9wb3NhbCBpcwphcHByb3ZlZCBhbmQgaXRzIHRpbWVsb2NrIGhhcyBwYXNzZWQuIENhbGxhYmxlIGJ5IGFueW9uZS4AAAAAEGV4ZWN1dGVfcmVjb3ZlcnkAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAVZ2V0X3JlY292ZXJ5X3Byb3Bvc2FsAAAAAAAAAAAAAAEAAAPoAAAH0AAAABBSZWNvdmVyeVByb3Bvc2Fs",
+        "AAAAAAAAAENMaWZldGltZSBvZiBjbGFpbSBsaW5rcyBnZW5lcmF0ZWQgd2l0aG91dCBhbiBleHBsaWNpdCBgZXhwaXJlc19hdGAuAAAAABVzZXRfZGVmYXVsdF9jbGFpbV90dGwAAAAAAAABAAAAAAAAAAh0dGxfc2VjcwAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAVZ2V0X2RlZmF1bHRfY2xhaW1fdHRsAAAAAAAAAAAAAAEAAAAG",
+        "AAAAAAAAAHdTZXRzIGhvdyBtYW55IGFzc29jaWF0ZSBjbGFpbXMgc3VwZXJ2aXNvcnMgbWF5IGlzc3VlIGluIHRvdGFsLgpBcHBsaWVzIHRvIGV2ZXJ5IHN1cGVydmlzb3Igd2l0aG91dCBhbiBpbmRpdmlkdWFsIHF1b3RhLgAAAAAac2V0X2RlZmF1bHRfaXNzdWFuY2VfcXVvdGEAAAAAAAEAAAAAAAAABXF1b3RhAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAaZ2V0X2RlZmF1bHRfaXNzdWFuY2VfcXVvdGEAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAASc2V0X2lzc3VhbmNlX3F1b3RhAAAAAAACAAAAAAAAAApzdXBlcnZpc29yAAAAAAATAAAAAAAAAAVxdW90YQAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAACtSZXR1cm5zIGAocXVvdGEsIGlzc3VlZClgIGZvciBgc3VwZXJ2aXNvcmAuAAAAABJnZXRfaXNzdWFuY2VfcXVvdGEAAAAAAAEAAAAAAAAACnN1cGVydmlzb3IAAAAAABMAAAABAAAD7QAAAAIAAAAEAAAABA==",
+        "AAAAAAAAAAAAAAAeZ2VuZXJhdGVfc3VwZXJ2aXNvcl9jbGFpbV9saW5rAAAAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAEAAAPpAAAD7gAAACAAAAAD",
+        "AAAAAAAAAHlJc3N1ZXMgYW4gYXNzb2NpYXRlIGludml0YXRpb24uIGBvcGVyYXRvcmAgbXVzdCBiZSB0aGUgYWRtaW4gb3IgYQpzdXBlcnZpc29yOyBzdXBlcnZpc29ycyBkcmF3IGZyb20gdGhlaXIgaXNzdWFuY2UgcXVvdGEuAAAAAAAAHWdlbmVyYXRlX2Fzc29jaWF0ZV9jbGFpbV9saW5rAAAAAAAAAwAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAEAAAPpAAAD7gAAACAAAAAD",
+        "AAAAAAAAASVSZWdpc3RlcnMgYW4gb3BlbiBhc3NvY2lhdGUgaW52aXRhdGlvbi4gYHNlY3JldF9oYXNoYCBpcyB0aGUgc2hhMjU2Cm9mIGEgcmFuZG9tIHNlY3JldCBjYXJyaWVkIGJ5IHRoZSBsaW5rOyBldmVyeSB1c2UgY291bnRzIGFnYWluc3QgdGhlCm9wZXJhdG9yJ3MgcXVvdGEuIFRoZSBzZWNyZXQgaXMgcHVibGljIG9uY2UgZmlyc3QgcmVkZWVtZWQsIHNvIG9wZW4KbGlua3Mgb25seSBncmFudCB0aGUgYXNzb2NpYXRlIHJvbGU7IHN1cGVydmlzb3JzIGFyZSBpbnZpdGVkIHdpdGgKcmVjaXBpZW50LWJvdW5kIGxpbmtzLgAAAAAAABxnZW5lcmF0ZV9vcGVuX2Fzc29jaWF0ZV9saW5rAAAABAAAAAAAAAALc2VjcmV0X2hhc2gAAAAD7gAAACAAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIbWF4X3VzZXMAAAAEAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEJSZXZva2VzIGFuIHVudXNlZCBjbGFpbSBsaW5rLiBDYWxsYWJsZSBieSB0aGUgYWRtaW4gb3IgdGhlIGlzc3Vlci4AAAAAABFyZXZva2VfY2xhaW1fbGluawAAAAAAAAIAAAAAAAAACnRva2VuX2hhc2gAAAAAA+4AAAAgAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAALNNYXJrcyBhIGNsYWltIGxpbmsgcGFzdCBpdHMgZXhwaXJ5IGFzIGV4cGlyZWQgYW5kIGVtaXRzIGBDbGFpbUV4cGAuCkNhbGxhYmxlIGJ5IGFueW9uZTsgYGNsYWltX25mdGAgcmVqZWN0cyBleHBpcmVkIGxpbmtzIG9uIGl0cyBvd24sIGJ1dApldmVudHMgb2YgYSBmYWlsZWQgY2FsbCBhcmUgbm90IHJlY29yZGVkLgAAAAARZXhwaXJlX2NsYWltX2xpbmsAAAAAAAABAAAAAAAAAAp0b2tlbl9oYXNoAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAQZ2V0X2NsYWltX3N0YXR1cwAAAAEAAAAAAAAACnRva2VuX2hhc2gAAAAAA+4AAAAgAAAAAQAAA+kAAAfQAAAAC0NsYWltU3RhdHVzAAAAAAM=",
+        "AAAAAAAAAIZSZWRlZW1zIGEgcmVjaXBpZW50LWJvdW5kIGNsYWltIGxpbmsuIFRoZSByZWNpcGllbnQgbXVzdCBhdXRob3JpemUsCnNvIHRoZSBsaW5rIGNhbid0IGJlIHJlZGVlbWVkIG9uIHRoZWlyIGJlaGFsZiBieSB3aG9ldmVyIGhvbGRzIGl0LgAAAAAACWNsYWltX25mdAAAAAAAAAIAAAAAAAAACnRva2VuX2hhc2gAAAAAA+4AAAAgAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAMNSZWRlZW1zIGFuIG9wZW4gY2xhaW0gbGluayBieSBwcmVzZW50aW5nIHRoZSBzZWNyZXQgd2hvc2Ugc2hhMjU2IHdhcwpyZWdpc3RlcmVkLiBUaGUgZmlyc3Qgd2FsbGV0cyB0byBwcmVzZW50IGl0ICh1cCB0byBgbWF4X3VzZXNgKSB3aW47CmFmdGVyIHRoZSBmaXJzdCByZWRlbXB0aW9uIHRoZSBzZWNyZXQgaXMgdmlzaWJsZSBvbiBjaGFpbi4AAAAAFWNsYWltX25mdF93aXRoX3NlY3JldAAAAAAAAAIAAAAAAAAABnNlY3JldAAAAAAADgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAAAAAAARcmV2b2tlX2NyZWRlbnRpYWwAAAAAAAACAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAAAAAAAB3JvbGVfaWQAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAMBTd2l0Y2hlcyBgcGF1c2VgLCBgdW5wYXVzZWAsIGBzZXRfcGF1c2VgLCBgcmV2b2tlX2NyZWRlbnRpYWxgIGFuZApgc2V0X2V2ZW50X2NvbnRyYWN0YCB0byBjb3VuY2lsIG1vZGU6IGFmdGVyd2FyZHMgdGhleSBvbmx5IHJ1bgp0aHJvdWdoIGFwcHJvdmVkIHByb3Bvc2Fscy4gRXZlcnkgbWVtYmVyIHJlY2VpdmVzIGBST0xFX0FETUlOYC4AAAALc2V0X2NvdW5jaWwAAAAAAgAAAAAAAAAHbWVtYmVycwAAAAPqAAAAEwAAAAAAAAAJdGhyZXNob2xkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAG9SZXR1cm5zIHRoZSBjb3VuY2lsIG1lbWJlcnMgYW5kIHRoZSBhcHByb3ZhbHMgYSBwcm9wb3NhbCBuZWVkcy4KQW4gZW1wdHkgc2V0IG1lYW5zIHRoZSBzaW5nbGUgYWRtaW4gYWN0cyBhbG9uZS4AAAAAC2dldF9jb3VuY2lsAAAAAAAAAAABAAAD7QAAAAIAAAPqAAAAEwAAAAQ=",
+        "AAAAAAAAAGZPcGVucyBhIHByb3Bvc2FsIGZvciBhIHByaXZpbGVnZWQgYWN0aW9uLiBUaGUgcHJvcG9zZXIncyBhcHByb3ZhbCBpcwpjb3VudGVkLiBSZXR1cm5zIHRoZSBwcm9wb3NhbCBpZC4AAAAAAA5wcm9wb3NlX2FjdGlvbgAAAAAAAwAAAAAAAAAGbWVtYmVyAAAAAAATAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAALQWRtaW5BY3Rpb24AAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAAAAAAAOYXBwcm92ZV9hY3Rpb24AAAAAAAIAAAAAAAAABm1lbWJlcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAFlSdW5zIGFuIGFwcHJvdmVkIHByb3Bvc2FsLiBPbmx5IGFwcHJvdmFscyBmcm9tIGN1cnJlbnQgbWVtYmVycyBjb3VudC4KQ2FsbGFibGUgYnkgYW55b25lLgAAAAAAAA5leGVjdXRlX2FjdGlvbgAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAKZ2V0X2FjdGlvbgAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAPoAAAH0AAAAA9Db3VuY2lsUHJvcG9zYWwA",
+        "AAAAAAAAAHJFeHRlbmRzIHRoZSBUVEwgb2YgYHdhbGxldGAncyByb2xlIG1hcCBhbmQgY3JlZGVudGlhbCB0b2tlbnMgc28KYXJjaGl2ZXJzIGNhbiBrZWVwIHRoZW0gYWxpdmUuIENhbGxhYmxlIGJ5IGFueW9uZS4AAAAAAA9idW1wX2NyZWRlbnRpYWwAAAAAAQAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAAAAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAAAAAAAB3JvbGVfaWQAAAAABAAAAAEAAAAB",
         "AAAAAAAAAAAAAAAJdG9rZW5fdXJpAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAABA=",
         "AAAAAAAAAAAAAAASc3VwcG9ydHNfaW50ZXJmYWNlAAAAAAABAAAAAAAAAAxpbnRlcmZhY2VfaWQAAAAQAAAAAQAAAAE=",
@@ -459,19 +1358,50 @@ export class Client extends ContractClient {
     );
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<null>,
-    transfer_admin: this.txFromJSON<null>,
-    pause: this.txFromJSON<null>,
-    unpause: this.txFromJSON<null>,
-    set_base_uri: this.txFromJSON<null>,
-    set_event_contract: this.txFromJSON<null>,
+    initialize: this.txFromJSON<Result<void>>,
+    propose_admin: this.txFromJSON<Result<void>>,
+    accept_admin: this.txFromJSON<Result<void>>,
+    cancel_admin_proposal: this.txFromJSON<Result<void>>,
+    get_admin: this.txFromJSON<Result<string>>,
+    get_pending_admin: this.txFromJSON<Option<PendingAdmin>>,
+    pause: this.txFromJSON<Result<void>>,
+    unpause: this.txFromJSON<Result<void>>,
+    set_pause: this.txFromJSON<Result<void>>,
+    pause_status: this.txFromJSON<PauseStatus>,
+    set_base_uri: this.txFromJSON<Result<void>>,
+    set_event_contract: this.txFromJSON<Result<void>>,
     get_event_contract: this.txFromJSON<Option<string>>,
-    authorize_contract: this.txFromJSON<null>,
-    recover_admin: this.txFromJSON<null>,
-    generate_supervisor_claim_link: this.txFromJSON<Buffer>,
-    generate_associate_claim_link: this.txFromJSON<Buffer>,
-    claim_nft: this.txFromJSON<u64>,
-    revoke_credential: this.txFromJSON<null>,
+    set_guardians: this.txFromJSON<Result<void>>,
+    get_guardians: this.txFromJSON<readonly [Array<string>, u32]>,
+    set_recovery_timing: this.txFromJSON<Result<void>>,
+    get_recovery_timing: this.txFromJSON<readonly [u64, u64]>,
+    propose_recovery: this.txFromJSON<Result<void>>,
+    approve_recovery: this.txFromJSON<Result<void>>,
+    veto_recovery: this.txFromJSON<Result<void>>,
+    execute_recovery: this.txFromJSON<Result<void>>,
+    get_recovery_proposal: this.txFromJSON<Option<RecoveryProposal>>,
+    set_default_claim_ttl: this.txFromJSON<Result<void>>,
+    get_default_claim_ttl: this.txFromJSON<u64>,
+    set_default_issuance_quota: this.txFromJSON<Result<void>>,
+    get_default_issuance_quota: this.txFromJSON<u32>,
+    set_issuance_quota: this.txFromJSON<Result<void>>,
+    get_issuance_quota: this.txFromJSON<readonly [u32, u32]>,
+    generate_supervisor_claim_link: this.txFromJSON<Result<Buffer>>,
+    generate_associate_claim_link: this.txFromJSON<Result<Buffer>>,
+    generate_open_associate_link: this.txFromJSON<Result<void>>,
+    revoke_claim_link: this.txFromJSON<Result<void>>,
+    expire_claim_link: this.txFromJSON<Result<void>>,
+    get_claim_status: this.txFromJSON<Result<ClaimStatus>>,
+    claim_nft: this.txFromJSON<Result<u64>>,
+    claim_nft_with_secret: this.txFromJSON<Result<u64>>,
+    revoke_credential: this.txFromJSON<Result<void>>,
+    set_council: this.txFromJSON<Result<void>>,
+    get_council: this.txFromJSON<readonly [Array<string>, u32]>,
+    propose_action: this.txFromJSON<Result<u64>>,
+    approve_action: this.txFromJSON<Result<void>>,
+    execute_action: this.txFromJSON<Result<void>>,
+    get_action: this.txFromJSON<Option<CouncilProposal>>,
+    bump_credential: this.txFromJSON<Result<void>>,
     has_role: this.txFromJSON<boolean>,
     token_uri: this.txFromJSON<string>,
     supports_interface: this.txFromJSON<boolean>,
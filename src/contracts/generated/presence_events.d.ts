@@ -12,17 +12,32 @@ export class Client {
     operator: string;
   }): Promise<any>;
   get_event(args: { event_id: bigint }): Promise<{ result: unknown }>;
-  register_presence(args: { event_id: bigint; attendee: string }): Promise<any>;
+  register_presence(args: {
+    event_id: bigint;
+    operator: string;
+    attendee: string;
+  }): Promise<any>;
+  register_presence_batch(args: {
+    event_id: bigint;
+    operator: string;
+    attendees: string[];
+  }): Promise<any>;
   has_presence(args: {
     event_id: bigint;
     attendee: string;
   }): Promise<{ result: unknown }>;
-  set_supervisor(args: { user: string; status: boolean }): Promise<any>;
   list_events(args: {
-    start_id: bigint;
+    cursor: bigint;
+    limit: number;
+  }): Promise<{ result: unknown }>;
+  list_upcoming(args: {
+    cursor: bigint;
+    limit: number;
+  }): Promise<{ result: unknown }>;
+  list_ongoing(args: {
+    cursor: bigint;
     limit: number;
   }): Promise<{ result: unknown }>;
-  list_upcoming(): Promise<{ result: unknown }>;
   list_closed(args: {
     cursor: bigint;
     limit: number;
@@ -31,9 +46,15 @@ export class Client {
     event_id: bigint;
     cursor: bigint;
     limit: number;
+    filter_active: boolean;
   }): Promise<{ result: unknown }>;
-  remove_presence(args: { event_id: bigint; attendee: string }): Promise<any>;
-  transfer_admin(args: { new_admin: string }): Promise<any>;
+  remove_presence(args: {
+    event_id: bigint;
+    operator: string;
+    attendee: string;
+  }): Promise<any>;
+  propose_admin(args: { new_admin: string; expires_at: bigint }): Promise<any>;
+  accept_admin(): Promise<any>;
 }
 export const networks: {
   testnet: {
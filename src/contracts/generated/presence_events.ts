@@ -37,17 +37,122 @@ export const networks = {
   },
 } as const;
 
+export const Errors = {
+  1: { message: "AdminNotSet" },
+  2: { message: "NotSupervisor" },
+  3: { message: "InvalidWindow" },
+  4: { message: "EventNotFound" },
+  5: { message: "TooEarly" },
+  6: { message: "OutsideWindow" },
+  7: { message: "AlreadyRegistered" },
+  8: { message: "AttendeeNotRegistered" },
+  9: { message: "OwnerRulesNotSet" },
+  10: { message: "NoCheckInSession" },
+  11: { message: "InvalidProof" },
+  12: { message: "VoucherKeyNotSet" },
+  13: { message: "VoucherAlreadyUsed" },
+  14: { message: "InvalidVoucher" },
+  15: { message: "EventNotClosed" },
+  16: { message: "CertificateAlreadyClaimed" },
+  17: { message: "CertificateNotFound" },
+  18: { message: "UriTooLong" },
+  19: { message: "EventCancelled" },
+  20: { message: "EventClosed" },
+  21: { message: "NotEventOrganizer" },
+  22: { message: "AlreadyOrganizer" },
+  23: { message: "OrganizerNotFound" },
+  24: { message: "AlreadyCheckedOut" },
+  25: { message: "BelowMinimumDuration" },
+  26: { message: "SeriesNotFound" },
+  27: { message: "InvalidRecurrence" },
+  28: { message: "NotEnrolled" },
+  29: { message: "CapacityReached" },
+  30: { message: "AlreadyEnrolled" },
+  31: { message: "InviteOnly" },
+  32: { message: "NoPendingAdmin" },
+  33: { message: "AdminProposalExpired" },
+  34: { message: "Paused" },
+  35: { message: "ProofAlreadyUsed" },
+  36: { message: "InvalidExpiry" },
+  37: { message: "CheckInSessionExpired" },
+};
+
 export type DataKey =
   | { tag: "EventById"; values: readonly [u64] }
-  | { tag: "Supervisor"; values: readonly [string] }
   | { tag: "Presence"; values: readonly [u64, string] }
   | { tag: "AttendeesCount"; values: readonly [u64] }
-  | { tag: "AttendeeByIndex"; values: readonly [u64, u64] };
+  | { tag: "AttendeeByIndex"; values: readonly [u64, u64] }
+  | { tag: "AttendeeIndex"; values: readonly [u64, string] }
+  | { tag: "CheckInSession"; values: readonly [u64] }
+  | { tag: "VoucherKey"; values: readonly [string] }
+  | { tag: "VoucherNonce"; values: readonly [string, u64] }
+  | { tag: "CertificateById"; values: readonly [u64] }
+  | { tag: "CertificateFor"; values: readonly [u64, string] }
+  | { tag: "CertificatesCount"; values: readonly [string] }
+  | { tag: "CertificateByOwnerIndex"; values: readonly [string, u64] }
+  | { tag: "CreatorEventsCount"; values: readonly [string] }
+  | { tag: "CreatorEventByIndex"; values: readonly [string, u64] }
+  | { tag: "SeriesById"; values: readonly [u64] }
+  | { tag: "ActiveAttendeesCount"; values: readonly [u64] }
+  | { tag: "Enrollment"; values: readonly [u64, string] }
+  | { tag: "EnrolledCount"; values: readonly [u64] }
+  | { tag: "WaitlistHead"; values: readonly [u64] }
+  | { tag: "WaitlistTail"; values: readonly [u64] }
+  | { tag: "WaitlistByIndex"; values: readonly [u64, u64] }
+  | { tag: "WaitlistPosition"; values: readonly [u64, string] }
+  | { tag: "AttendeeEventsCount"; values: readonly [string] }
+  | { tag: "AttendeeEventByIndex"; values: readonly [string, u64] }
+  | { tag: "AttendeeEventPosition"; values: readonly [string, u64] }
+  | { tag: "EventsByDay"; values: readonly [u64] }
+  | { tag: "LongEvents"; values: void }
+  | { tag: "CheckInNonce"; values: readonly [u64, string] };
+
+export enum EventStatus {
+  Scheduled = 0,
+  Open = 1,
+  Closed = 2,
+  Cancelled = 3,
+}
 
 export interface EventData {
+  creator: string;
+  /**
+   * Registration opens this many seconds before `start_ts`.
+   */
+  early_checkin_secs: u64;
   end_ts: u64;
+  /**
+   * Only enrolled attendees may be registered.
+   */
+  invite_only: boolean;
+  /**
+   * Registration stays open this many seconds after `end_ts`.
+   */
+  late_grace_secs: u64;
+  /**
+   * Maximum enrolled and registered attendees (0 = unlimited).
+   */
+  max_capacity: u32;
+  /**
+   * Attendance shorter than this does not count as present (0 disables).
+   */
+  min_duration_secs: u64;
   name: string;
+  /**
+   * Co-organizers allowed to manage attendance alongside the creator.
+   */
+  organizers: Array<string>;
+  /**
+   * Series this event is a session of, if any.
+   */
+  series_id: Option<u64>;
   start_ts: u64;
+  /**
+   * Stored as `Scheduled` or `Cancelled`; `Open` and `Closed` are derived
+   * from the ledger time whenever the event is read. An event is `Closed`
+   * once its late grace period has elapsed.
+   */
+  status: EventStatus;
 }
 
 export interface EventSummary {
@@ -55,20 +160,140 @@ export interface EventSummary {
   id: u64;
   name: string;
   start_ts: u64;
+  status: EventStatus;
+}
+
+export enum EnrollmentStatus {
+  Enrolled = 0,
+  Waitlisted = 1,
+}
+
+/**
+ * A recurring course or event made of individual session events.
+ */
+export interface SeriesData {
+  creator: string;
+  name: string;
+  sessions: Array<u64>;
+}
+
+export interface SessionWindow {
+  end_ts: u64;
+  start_ts: u64;
+}
+
+/**
+ * Attendance of one attendee across a series, e.g. 24 of 30 sessions.
+ */
+export interface SeriesAttendance {
+  attended: u32;
+  /**
+   * Sessions already closed.
+   */
+  held: u32;
+  /**
+   * Sessions not cancelled.
+   */
+  total: u32;
 }
 
 export interface AttendeeInfo {
   active: boolean;
   address: string;
+  checked_out_at: Option<u64>;
+  /**
+   * Time attended within `[start_ts, end_ts]`, set on check-out.
+   */
+  duration_secs: u64;
+  registered_at: u64;
+}
+
+/**
+ * A page of a listing. Pass `next_cursor` back as `cursor` to continue;
+ * `None` means the listing is exhausted.
+ */
+export interface EventPage {
+  items: Array<EventSummary>;
+  next_cursor: Option<u64>;
+}
+
+export interface AttendeePage {
+  items: Array<AttendeeInfo>;
+  next_cursor: Option<u64>;
+}
+
+export interface CertificatePage {
+  items: Array<CertificateData>;
+  next_cursor: Option<u64>;
+}
+
+/**
+ * How a self check-in session authenticates attendees.
+ */
+export type CheckInVerifier =
+  | { tag: "RotatingCode"; values: readonly [Buffer, u64] }
+  | { tag: "SigningKey"; values: readonly [Buffer] };
+
+export interface CheckInSession {
+  /**
+   * Unique per opened session; bound into `checkin_message`.
+   */
+  nonce: u64;
+  opened_at: u64;
+  opened_by: string;
+  verifier: CheckInVerifier;
+}
+
+/**
+ * Offline attendance voucher signed by `supervisor`'s voucher key.
+ */
+export interface Voucher {
+  attendee: string;
+  event_id: u64;
+  issued_at: u64;
+  nonce: u64;
+  supervisor: string;
+}
+
+/**
+ * Non-transferable proof that `owner` attended `event_id`.
+ */
+export interface CertificateData {
+  duration_secs: u64;
+  event_id: u64;
+  id: u64;
+  issued_at: u64;
+  owner: string;
   registered_at: u64;
 }
 
+/**
+ * Emergency-stop flags. owner-rules has its own flags and storage; each
+ * contract is paused separately. Each flag halts a group of
+ * entrypoints; see `set_pause`.
+ */
+export interface PauseStatus {
+  admin: boolean;
+  claims: boolean;
+  event_creation: boolean;
+  issuance: boolean;
+  registration: boolean;
+}
+
+/**
+ * Admin handover awaiting acceptance by `new_admin`.
+ */
+export interface PendingAdmin {
+  expires_at: u64;
+  new_admin: string;
+}
+
 export interface Client {
   /**
-   * Construct and simulate a set_supervisor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_owner_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_supervisor: (
-    { user, status }: { user: string; status: boolean },
+  set_owner_rules: (
+    { contract }: { contract: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -85,7 +310,27 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_owner_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_owner_rules: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
    * Construct and simulate a create_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -113,7 +358,146 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<u64>>;
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a create_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Creates a series whose sessions are the given explicit windows.
+   */
+  create_series: (
+    {
+      name,
+      operator,
+      sessions,
+    }: { name: string; operator: string; sessions: Array<SessionWindow> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a create_recurring_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Creates a series of `count` sessions lasting `duration_secs`, the
+   * first starting at `first_start_ts` and each following one
+   * `interval_secs` later (e.g. 604800 for weekly classes).
+   */
+  create_recurring_series: (
+    {
+      name,
+      operator,
+      first_start_ts,
+      duration_secs,
+      interval_secs,
+      count,
+    }: {
+      name: string;
+      operator: string;
+      first_start_ts: u64;
+      duration_secs: u64;
+      interval_secs: u64;
+      count: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a get_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_series: (
+    { series_id }: { series_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<SeriesData>>>;
+
+  /**
+   * Construct and simulate a list_series_sessions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  list_series_sessions: (
+    { series_id }: { series_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<EventSummary>>>>;
+
+  /**
+   * Construct and simulate a get_series_attendance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sessions of the series `attendee` counts as present at, against the
+   * sessions held so far and the total planned.
+   */
+  get_series_attendance: (
+    { series_id, attendee }: { series_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<SeriesAttendance>>>;
 
   /**
    * Construct and simulate a get_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -139,10 +523,14 @@ export interface Client {
   ) => Promise<AssembledTransaction<Option<EventData>>>;
 
   /**
-   * Construct and simulate a register_presence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a update_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  register_presence: (
-    { event_id, attendee }: { event_id: u64; attendee: string },
+  update_event: (
+    {
+      event_id,
+      operator,
+      name,
+    }: { event_id: u64; operator: string; name: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -159,13 +547,18 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a has_presence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a reschedule_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  has_presence: (
-    { event_id, attendee }: { event_id: u64; attendee: string },
+  reschedule_event: (
+    {
+      event_id,
+      operator,
+      start_ts,
+      end_ts,
+    }: { event_id: u64; operator: string; start_ts: u64; end_ts: u64 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -182,13 +575,25 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<boolean>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a list_attendees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_checkin_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Overrides how early attendees may register and how long after the
+   * end registration stays open for this event.
    */
-  list_attendees: (
-    { event_id, cursor, limit }: { event_id: u64; cursor: u64; limit: u32 },
+  set_checkin_window: (
+    {
+      event_id,
+      operator,
+      early_checkin_secs,
+      late_grace_secs,
+    }: {
+      event_id: u64;
+      operator: string;
+      early_checkin_secs: u64;
+      late_grace_secs: u64;
+    },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -205,13 +610,18 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<AttendeeInfo>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a remove_presence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_min_duration transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the minimum checked-in time an attendee needs to count as present.
    */
-  remove_presence: (
-    { event_id, attendee }: { event_id: u64; attendee: string },
+  set_min_duration: (
+    {
+      event_id,
+      operator,
+      min_duration_secs,
+    }: { event_id: u64; operator: string; min_duration_secs: u64 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -228,13 +638,27 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a list_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_enrollment_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets whether the event only admits enrolled attendees and its
+   * capacity (0 = unlimited). Seats freed by a larger capacity go to the
+   * waitlist in order, up to one bounded pass; `promote_waitlist` fills
+   * the rest.
    */
-  list_events: (
-    { start_id, limit }: { start_id: u64; limit: u32 },
+  set_enrollment_policy: (
+    {
+      event_id,
+      operator,
+      invite_only,
+      max_capacity,
+    }: {
+      event_id: u64;
+      operator: string;
+      invite_only: boolean;
+      max_capacity: u32;
+    },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -251,33 +675,46 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<EventSummary>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a list_upcoming transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a promote_waitlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Promotes waitlisted attendees in order while the event has free seats,
+   * examining at most `MAX_PAGE_SCAN` waitlist slots. Anyone may call it
+   * to continue a promotion a bounded pass left unfinished. Returns the
+   * number of attendees promoted.
    */
-  list_upcoming: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  promote_waitlist: (
+    { event_id }: { event_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<EventSummary>>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
 
   /**
-   * Construct and simulate a list_closed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a enroll_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Adds attendees to the enrollment list. Once capacity is reached the
+   * remaining ones are waitlisted. Already enrolled attendees are skipped.
    */
-  list_closed: (
-    { cursor, limit }: { cursor: u64; limit: u32 },
+  enroll_batch: (
+    {
+      event_id,
+      operator,
+      attendees,
+    }: { event_id: u64; operator: string; attendees: Array<string> },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -294,13 +731,14 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<EventSummary>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a self_enroll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Self-enrollment on events that are not invite-only.
    */
-  transfer_admin: (
-    { new_admin }: { new_admin: string },
+  self_enroll: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -317,12 +755,1355 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Result<EnrollmentStatus>>>;
+
+  /**
+   * Construct and simulate a withdraw_enrollment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraws an enrollment or waitlist entry. A freed seat goes to the
+   * first attendee still on the waitlist, unless a lowered capacity is
+   * still exceeded.
+   */
+  withdraw_enrollment: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_enrollment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_enrollment: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<EnrollmentStatus>>>;
+
+  /**
+   * Construct and simulate a enrolled_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  enrolled_count: (
+    { event_id }: { event_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_default_checkin_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Window applied to events created from now on.
+   */
+  set_default_checkin_window: (
+    {
+      early_checkin_secs,
+      late_grace_secs,
+    }: { early_checkin_secs: u64; late_grace_secs: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_default_checkin_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns `(early_checkin_secs, late_grace_secs)`.
+   */
+  get_default_checkin_window: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [u64, u64]>>;
+
+  /**
+   * Construct and simulate a add_organizer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_organizer: (
+    {
+      event_id,
+      operator,
+      organizer,
+    }: { event_id: u64; operator: string; organizer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a remove_organizer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_organizer: (
+    {
+      event_id,
+      operator,
+      organizer,
+    }: { event_id: u64; operator: string; organizer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a cancel_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_event: (
+    { event_id, operator }: { event_id: u64; operator: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a bump_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extends the TTL of an event record, its attendee counters and check-in
+   * session. Attendee rows are bumped separately via `bump_attendees`.
+   */
+  bump_event: (
+    { event_id }: { event_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a bump_attendees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extends the TTL of up to `limit` attendee rows of an event, starting
+   * at index `cursor`, together with their presence flags.
+   */
+  bump_attendees: (
+    { event_id, cursor, limit }: { event_id: u64; cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a register_presence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  register_presence: (
+    {
+      event_id,
+      operator,
+      attendee,
+    }: { event_id: u64; operator: string; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a register_presence_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Registers several attendees at once. Already registered attendees are
+   * skipped; attendees the enrollment policy does not admit (not enrolled
+   * or over capacity) are skipped too and returned.
+   */
+  register_presence_batch: (
+    {
+      event_id,
+      operator,
+      attendees,
+    }: { event_id: u64; operator: string; attendees: Array<string> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<string>>>>;
+
+  /**
+   * Construct and simulate a register_checkout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  register_checkout: (
+    {
+      event_id,
+      operator,
+      attendee,
+    }: { event_id: u64; operator: string; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a register_checkout_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Checks out every listed attendee that is registered and not yet
+   * checked out; the others are skipped.
+   */
+  register_checkout_batch: (
+    {
+      event_id,
+      operator,
+      attendees,
+    }: { event_id: u64; operator: string; attendees: Array<string> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_attendance_duration transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Attendance duration in seconds, or `None` if the attendee has not
+   * checked out.
+   */
+  get_attendance_duration: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<u64>>>;
+
+  /**
+   * Construct and simulate a is_present transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether the attendee is registered and meets the event's minimum
+   * duration.
+   */
+  is_present: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a open_checkin_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Opens (or rotates) the self check-in session of an event. Attendees
+   * then call `check_in` with a proof accepted by `verifier`.
+   */
+  open_checkin_session: (
+    {
+      event_id,
+      operator,
+      verifier,
+    }: { event_id: u64; operator: string; verifier: CheckInVerifier },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a close_checkin_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  close_checkin_session: (
+    { event_id, operator }: { event_id: u64; operator: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_checkin_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_checkin_session: (
+    { event_id }: { event_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<CheckInSession>>>;
+
+  /**
+   * Construct and simulate a check_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Self check-in. With `RotatingCode` the proof is the 32-byte code of
+   * the current (or previous) window; with `SigningKey` it is an ed25519
+   * signature over `checkin_message(event_id, attendee, nonce)`, usable
+   * once per session. A signature that does not verify aborts the
+   * invocation in the host instead of returning `InvalidProof`.
+   */
+  check_in: (
+    {
+      event_id,
+      attendee,
+      proof,
+    }: { event_id: u64; attendee: string; proof: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a checkin_message transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Message a session signing key must sign to admit `attendee`; `nonce`
+   * is the one of the currently open session.
+   */
+  checkin_message: (
+    {
+      event_id,
+      attendee,
+      nonce,
+    }: { event_id: u64; attendee: string; nonce: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a set_voucher_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Registers the ed25519 key `operator` signs offline vouchers with.
+   */
+  set_voucher_key: (
+    { operator, public_key }: { operator: string; public_key: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a remove_voucher_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_voucher_key: (
+    { operator }: { operator: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_voucher_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_voucher_key: (
+    { supervisor }: { supervisor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
+
+  /**
+   * Construct and simulate a redeem_voucher transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeems a supervisor-signed attendance voucher. Anyone may submit it;
+   * the attendee is registered as of `voucher.issued_at`. A voucher
+   * issued in the future is `InvalidVoucher`; a signature that does not
+   * verify aborts the invocation in the host, so every typed check runs
+   * before verification.
+   */
+  redeem_voucher: (
+    { voucher, signature }: { voucher: Voucher; signature: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a voucher_message transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Message a supervisor's voucher key must sign.
+   */
+  voucher_message: (
+    {
+      event_id,
+      attendee,
+      issued_at,
+      nonce,
+    }: { event_id: u64; attendee: string; issued_at: u64; nonce: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a has_presence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  has_presence: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a list_attendees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Attendees in registration order starting at index `cursor`. With
+   * `filter_active` removed attendees are skipped.
+   */
+  list_attendees: (
+    {
+      event_id,
+      cursor,
+      limit,
+      filter_active,
+    }: { event_id: u64; cursor: u64; limit: u32; filter_active: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<AttendeePage>>;
+
+  /**
+   * Construct and simulate a active_attendees_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of registered attendees, excluding removed ones.
+   */
+  active_attendees_count: (
+    { event_id }: { event_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a remove_presence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_presence: (
+    {
+      event_id,
+      operator,
+      attendee,
+    }: { event_id: u64; operator: string; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a list_events_for_attendee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Events `attendee` is currently registered at. Removals move the last
+   * entry into the freed slot, so the order is not chronological.
+   */
+  list_events_for_attendee: (
+    { attendee, cursor, limit }: { attendee: string; cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a attendance_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  attendance_count: (
+    { attendee }: { attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a list_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Events by ascending id, starting at id `cursor`.
+   */
+  list_events: (
+    { cursor, limit }: { cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a list_events_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  list_events_by_creator: (
+    { creator, cursor, limit }: { creator: string; cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a list_upcoming transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Events not yet started, soonest first.
+   */
+  list_upcoming: (
+    { cursor, limit }: { cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a list_in_range transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Events starting within `[from_ts, to_ts]`, by start time.
+   */
+  list_in_range: (
+    {
+      from_ts,
+      to_ts,
+      cursor,
+      limit,
+    }: { from_ts: u64; to_ts: u64; cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a list_ongoing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open events (`start_ts <= now <= end_ts + late_grace_secs`), so
+   * attendees can still register during the grace period: first those
+   * from the recent day index, then long events.
+   */
+  list_ongoing: (
+    { cursor, limit }: { cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a list_closed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Closed events from the newest id down, starting at id `cursor`
+   * (0 starts from the most recent event).
+   */
+  list_closed: (
+    { cursor, limit }: { cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventPage>>;
+
+  /**
+   * Construct and simulate a set_certificate_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_certificate_base_uri: (
+    { new_uri }: { new_uri: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a claim_certificate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mints the soulbound attendance certificate of a closed event to one of
+   * its active attendees. Certificates have no transfer entrypoint.
+   */
+  claim_certificate: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+
+  /**
+   * Construct and simulate a get_certificate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_certificate: (
+    { cert_id }: { cert_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<CertificateData>>>;
+
+  /**
+   * Construct and simulate a certificate_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  certificate_for: (
+    { event_id, attendee }: { event_id: u64; attendee: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<u64>>>;
+
+  /**
+   * Construct and simulate a certificate_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  certificate_count: (
+    { owner }: { owner: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a list_certificates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  list_certificates: (
+    { owner, cursor, limit }: { owner: string; cursor: u64; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<CertificatePage>>;
+
+  /**
+   * Construct and simulate a certificate_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Metadata URI of a certificate: the certificate base URI followed by
+   * the certificate id.
+   */
+  certificate_uri: (
+    { cert_id }: { cert_id: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Raises every pause flag.
+   */
+  pause: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unpause: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets each pause flag individually:
+   * - `event_creation`: creating, editing, rescheduling, configuring and
+   * cancelling events and series, including their organizers.
+   * - `registration`: enrollment, presence and its removal, checkout,
+   * check-in and vouchers.
+   * - `issuance`: opening and closing check-in sessions and registering
+   * and removing voucher keys.
+   * - `claims`: certificate claims.
+   * - `admin`: `propose_admin` and `accept_admin`.
+   *
+   * Cancelling an admin proposal stays available.
+   */
+  set_pause: (
+    { status }: { status: PauseStatus },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a pause_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pause_status: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PauseStatus>>;
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * First step of an admin handover. Nothing changes until `new_admin`
+   * calls `accept_admin` before `expires_at`.
+   */
+  propose_admin: (
+    { new_admin, expires_at }: { new_admin: string; expires_at: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a cancel_admin_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_admin_proposal: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns the outstanding proposal, if any. An expired proposal stays
+   * visible until it is cancelled or replaced.
+   */
+  get_pending_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PendingAdmin>>>;
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
     /** Constructor/Initialization Args for the contract's `__constructor` method */
-    { admin }: { admin: string },
+    { admin, owner_rules }: { admin: string; owner_rules: string },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -334,42 +2115,177 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       },
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy({ admin }, options);
+    return ContractClient.deploy({ admin, owner_rules }, options);
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAACUV2ZW50QnlJZAAAAAAAAAEAAAAGAAAAAQAAAAAAAAAKU3VwZXJ2aXNvcgAAAAAAAQAAABMAAAABAAAAAAAAAAhQcmVzZW5jZQAAAAIAAAAGAAAAEwAAAAEAAAAAAAAADkF0dGVuZGVlc0NvdW50AAAAAAABAAAABgAAAAEAAAAAAAAAD0F0dGVuZGVlQnlJbmRleAAAAAACAAAABgAAAAY=",
-        "AAAAAQAAAAAAAAAAAAAACUV2ZW50RGF0YQAAAAAAAAMAAAAAAAAABmVuZF90cwAAAAAABgAAAAAAAAAEbmFtZQAAABAAAAAAAAAACHN0YXJ0X3RzAAAABg==",
-        "AAAAAQAAAAAAAAAAAAAADEV2ZW50U3VtbWFyeQAAAAQAAAAAAAAABmVuZF90cwAAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhzdGFydF90cwAAAAY=",
-        "AAAAAQAAAAAAAAAAAAAADEF0dGVuZGVlSW5mbwAAAAMAAAAAAAAABmFjdGl2ZQAAAAAAAQAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAA1yZWdpc3RlcmVkX2F0AAAAAAAABg==",
-        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAOc2V0X3N1cGVydmlzb3IAAAAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAZzdGF0dXMAAAAAAAEAAAAA",
-        "AAAAAAAAAAAAAAAMY3JlYXRlX2V2ZW50AAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACHN0YXJ0X3RzAAAABgAAAAAAAAAGZW5kX3RzAAAAAAAGAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAABg==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAJQAAAAAAAAALQWRtaW5Ob3RTZXQAAAAAAQAAAAAAAAANTm90U3VwZXJ2aXNvcgAAAAAAAAIAAAAAAAAADUludmFsaWRXaW5kb3cAAAAAAAADAAAAAAAAAA1FdmVudE5vdEZvdW5kAAAAAAAABAAAAAAAAAAIVG9vRWFybHkAAAAFAAAAAAAAAA1PdXRzaWRlV2luZG93AAAAAAAABgAAAAAAAAARQWxyZWFkeVJlZ2lzdGVyZWQAAAAAAAAHAAAAAAAAABVBdHRlbmRlZU5vdFJlZ2lzdGVyZWQAAAAAAAAIAAAAAAAAABBPd25lclJ1bGVzTm90U2V0AAAACQAAAAAAAAAQTm9DaGVja0luU2Vzc2lvbgAAAAoAAAAAAAAADEludmFsaWRQcm9vZgAAAAsAAAAAAAAAEFZvdWNoZXJLZXlOb3RTZXQAAAAMAAAAAAAAABJWb3VjaGVyQWxyZWFkeVVzZWQAAAAAAA0AAAAAAAAADkludmFsaWRWb3VjaGVyAAAAAAAOAAAAAAAAAA5FdmVudE5vdENsb3NlZAAAAAAADwAAAAAAAAAZQ2VydGlmaWNhdGVBbHJlYWR5Q2xhaW1lZAAAAAAAABAAAAAAAAAAE0NlcnRpZmljYXRlTm90Rm91bmQAAAAAEQAAAAAAAAAKVXJpVG9vTG9uZwAAAAAAEgAAAAAAAAAORXZlbnRDYW5jZWxsZWQAAAAAABMAAAAAAAAAC0V2ZW50Q2xvc2VkAAAAABQAAAAAAAAAEU5vdEV2ZW50T3JnYW5pemVyAAAAAAAAFQAAAAAAAAAQQWxyZWFkeU9yZ2FuaXplcgAAABYAAAAAAAAAEU9yZ2FuaXplck5vdEZvdW5kAAAAAAAAFwAAAAAAAAARQWxyZWFkeUNoZWNrZWRPdXQAAAAAAAAYAAAAAAAAABRCZWxvd01pbmltdW1EdXJhdGlvbgAAABkAAAAAAAAADlNlcmllc05vdEZvdW5kAAAAAAAaAAAAAAAAABFJbnZhbGlkUmVjdXJyZW5jZQAAAAAAABsAAAAAAAAAC05vdEVucm9sbGVkAAAAABwAAAAAAAAAD0NhcGFjaXR5UmVhY2hlZAAAAAAdAAAAAAAAAA9BbHJlYWR5RW5yb2xsZWQAAAAAHgAAAAAAAAAKSW52aXRlT25seQAAAAAAHwAAAAAAAAAOTm9QZW5kaW5nQWRtaW4AAAAAACAAAAAAAAAAFEFkbWluUHJvcG9zYWxFeHBpcmVkAAAAIQAAAAAAAAAGUGF1c2VkAAAAAAAiAAAAAAAAABBQcm9vZkFscmVhZHlVc2VkAAAAIwAAAAAAAAANSW52YWxpZEV4cGlyeQAAAAAAACQAAAAAAAAAFUNoZWNrSW5TZXNzaW9uRXhwaXJlZAAAAAAAACU=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAHAAAAAEAAAAAAAAACUV2ZW50QnlJZAAAAAAAAAEAAAAGAAAAAQAAAAAAAAAIUHJlc2VuY2UAAAACAAAABgAAABMAAAABAAAAAAAAAA5BdHRlbmRlZXNDb3VudAAAAAAAAQAAAAYAAAABAAAAAAAAAA9BdHRlbmRlZUJ5SW5kZXgAAAAAAgAAAAYAAAAGAAAAAQAAAAAAAAANQXR0ZW5kZWVJbmRleAAAAAAAAAIAAAAGAAAAEwAAAAEAAAAAAAAADkNoZWNrSW5TZXNzaW9uAAAAAAABAAAABgAAAAEAAAAAAAAAClZvdWNoZXJLZXkAAAAAAAEAAAATAAAAAQAAAAAAAAAMVm91Y2hlck5vbmNlAAAAAgAAABMAAAAGAAAAAQAAAAAAAAAPQ2VydGlmaWNhdGVCeUlkAAAAAAEAAAAGAAAAAQAAAAAAAAAOQ2VydGlmaWNhdGVGb3IAAAAAAAIAAAAGAAAAEwAAAAEAAAAAAAAAEUNlcnRpZmljYXRlc0NvdW50AAAAAAAAAQAAABMAAAABAAAAAAAAABdDZXJ0aWZpY2F0ZUJ5T3duZXJJbmRleAAAAAACAAAAEwAAAAYAAAABAAAAAAAAABJDcmVhdG9yRXZlbnRzQ291bnQAAAAAAAEAAAATAAAAAQAAAAAAAAATQ3JlYXRvckV2ZW50QnlJbmRleAAAAAACAAAAEwAAAAYAAAABAAAAAAAAAApTZXJpZXNCeUlkAAAAAAABAAAABgAAAAEAAAAAAAAAFEFjdGl2ZUF0dGVuZGVlc0NvdW50AAAAAQAAAAYAAAABAAAAAAAAAApFbnJvbGxtZW50AAAAAAACAAAABgAAABMAAAABAAAAAAAAAA1FbnJvbGxlZENvdW50AAAAAAAAAQAAAAYAAAABAAAAAAAAAAxXYWl0bGlzdEhlYWQAAAABAAAABgAAAAEAAAAAAAAADFdhaXRsaXN0VGFpbAAAAAEAAAAGAAAAAQAAAAAAAAAPV2FpdGxpc3RCeUluZGV4AAAAAAIAAAAGAAAABgAAAAEAAAAnV2FpdGxpc3Qgc2xvdCBvZiBhIHdhaXRsaXN0ZWQgYXR0ZW5kZWUuAAAAABBXYWl0bGlzdFBvc2l0aW9uAAAAAgAAAAYAAAATAAAAAQAAAAAAAAATQXR0ZW5kZWVFdmVudHNDb3VudAAAAAABAAAAEwAAAAEAAAAAAAAAFEF0dGVuZGVlRXZlbnRCeUluZGV4AAAAAgAAABMAAAAGAAAAAQAAAAAAAAAVQXR0ZW5kZWVFdmVudFBvc2l0aW9uAAAAAAAAAgAAABMAAAAGAAAAAQAAAD9gKHN0YXJ0X3RzLCBldmVudF9pZClgIG9mIHRoZSBldmVudHMgc3RhcnRpbmcgb24gYSBkYXksIHNvcnRlZC4AAAAAC0V2ZW50c0J5RGF5AAAAAAEAAAAGAAAAAAAAADlJZHMgb2YgZXZlbnRzIG9wZW4gbG9uZ2VyIHRoYW4gYE1BWF9FVkVOVF9EVVJBVElPTl9TRUNTYC4AAAAAAAAKTG9uZ0V2ZW50cwAAAAAAAQAAAEFOb25jZSBvZiB0aGUgbGFzdCBzZXNzaW9uIGEgc2lnbmVkIGNoZWNrLWluIG9mIHRoZSBhdHRlbmRlZSB1c2VkLgAAAAAAAAxDaGVja0luTm9uY2UAAAACAAAABgAAABM=",
+        "AAAAAwAAAAAAAAAAAAAAC0V2ZW50U3RhdHVzAAAAAAQAAAAAAAAACVNjaGVkdWxlZAAAAAAAAAAAAAAAAAAABE9wZW4AAAABAAAAAAAAAAZDbG9zZWQAAAAAAAIAAAAAAAAACUNhbmNlbGxlZAAAAAAAAAM=",
+        "AAAAAQAAAAAAAAAAAAAACUV2ZW50RGF0YQAAAAAAAAwAAAAAAAAAB2NyZWF0b3IAAAAAEwAAADdSZWdpc3RyYXRpb24gb3BlbnMgdGhpcyBtYW55IHNlY29uZHMgYmVmb3JlIGBzdGFydF90c2AuAAAAABJlYXJseV9jaGVja2luX3NlY3MAAAAAAAYAAAAAAAAABmVuZF90cwAAAAAABgAAACpPbmx5IGVucm9sbGVkIGF0dGVuZGVlcyBtYXkgYmUgcmVnaXN0ZXJlZC4AAAAAAAtpbnZpdGVfb25seQAAAAABAAAAOVJlZ2lzdHJhdGlvbiBzdGF5cyBvcGVuIHRoaXMgbWFueSBzZWNvbmRzIGFmdGVyIGBlbmRfdHNgLgAAAAAAAA9sYXRlX2dyYWNlX3NlY3MAAAAABgAAADpNYXhpbXVtIGVucm9sbGVkIGFuZCByZWdpc3RlcmVkIGF0dGVuZGVlcyAoMCA9IHVubGltaXRlZCkuAAAAAAAMbWF4X2NhcGFjaXR5AAAABAAAAERBdHRlbmRhbmNlIHNob3J0ZXIgdGhhbiB0aGlzIGRvZXMgbm90IGNvdW50IGFzIHByZXNlbnQgKDAgZGlzYWJsZXMpLgAAABFtaW5fZHVyYXRpb25fc2VjcwAAAAAAAAYAAAAAAAAABG5hbWUAAAAQAAAAQUNvLW9yZ2FuaXplcnMgYWxsb3dlZCB0byBtYW5hZ2UgYXR0ZW5kYW5jZSBhbG9uZ3NpZGUgdGhlIGNyZWF0b3IuAAAAAAAACm9yZ2FuaXplcnMAAAAAA+oAAAATAAAAKlNlcmllcyB0aGlzIGV2ZW50IGlzIGEgc2Vzc2lvbiBvZiwgaWYgYW55LgAAAAAACXNlcmllc19pZAAAAAAAA+gAAAAGAAAAAAAAAAhzdGFydF90cwAAAAYAAACzU3RvcmVkIGFzIGBTY2hlZHVsZWRgIG9yIGBDYW5jZWxsZWRgOyBgT3BlbmAgYW5kIGBDbG9zZWRgIGFyZSBkZXJpdmVkCmZyb20gdGhlIGxlZGdlciB0aW1lIHdoZW5ldmVyIHRoZSBldmVudCBpcyByZWFkLiBBbiBldmVudCBpcyBgQ2xvc2VkYApvbmNlIGl0cyBsYXRlIGdyYWNlIHBlcmlvZCBoYXMgZWxhcHNlZC4AAAAABnN0YXR1cwAAAAAH0AAAAAtFdmVudFN0YXR1cwA=",
+        "AAAAAQAAAAAAAAAAAAAADEV2ZW50U3VtbWFyeQAAAAUAAAAAAAAABmVuZF90cwAAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhzdGFydF90cwAAAAYAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtFdmVudFN0YXR1cwA=",
+        "AAAAAwAAAAAAAAAAAAAAEEVucm9sbG1lbnRTdGF0dXMAAAACAAAAAAAAAAhFbnJvbGxlZAAAAAAAAAAAAAAACldhaXRsaXN0ZWQAAAAAAAE=",
+        "AAAAAQAAAD5BIHJlY3VycmluZyBjb3Vyc2Ugb3IgZXZlbnQgbWFkZSBvZiBpbmRpdmlkdWFsIHNlc3Npb24gZXZlbnRzLgAAAAAAAAAAAApTZXJpZXNEYXRhAAAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhzZXNzaW9ucwAAA+oAAAAG",
+        "AAAAAQAAAAAAAAAAAAAADVNlc3Npb25XaW5kb3cAAAAAAAACAAAAAAAAAAZlbmRfdHMAAAAAAAYAAAAAAAAACHN0YXJ0X3RzAAAABg==",
+        "AAAAAQAAAENBdHRlbmRhbmNlIG9mIG9uZSBhdHRlbmRlZSBhY3Jvc3MgYSBzZXJpZXMsIGUuZy4gMjQgb2YgMzAgc2Vzc2lvbnMuAAAAAAAAAAAQU2VyaWVzQXR0ZW5kYW5jZQAAAAMAAAAAAAAACGF0dGVuZGVkAAAABAAAABhTZXNzaW9ucyBhbHJlYWR5IGNsb3NlZC4AAAAEaGVsZAAAAAQAAAAXU2Vzc2lvbnMgbm90IGNhbmNlbGxlZC4AAAAABXRvdGFsAAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAADEF0dGVuZGVlSW5mbwAAAAUAAAAAAAAABmFjdGl2ZQAAAAAAAQAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAA5jaGVja2VkX291dF9hdAAAAAAD6AAAAAYAAAA8VGltZSBhdHRlbmRlZCB3aXRoaW4gYFtzdGFydF90cywgZW5kX3RzXWAsIHNldCBvbiBjaGVjay1vdXQuAAAADWR1cmF0aW9uX3NlY3MAAAAAAAAGAAAAAAAAAA1yZWdpc3RlcmVkX2F0AAAAAAAABg==",
+        "AAAAAQAAAGxBIHBhZ2Ugb2YgYSBsaXN0aW5nLiBQYXNzIGBuZXh0X2N1cnNvcmAgYmFjayBhcyBgY3Vyc29yYCB0byBjb250aW51ZTsKYE5vbmVgIG1lYW5zIHRoZSBsaXN0aW5nIGlzIGV4aGF1c3RlZC4AAAAAAAAACUV2ZW50UGFnZQAAAAAAAAIAAAAAAAAABWl0ZW1zAAAAAAAD6gAAB9AAAAAMRXZlbnRTdW1tYXJ5AAAAAAAAAAtuZXh0X2N1cnNvcgAAAAPoAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAADEF0dGVuZGVlUGFnZQAAAAIAAAAAAAAABWl0ZW1zAAAAAAAD6gAAB9AAAAAMQXR0ZW5kZWVJbmZvAAAAAAAAAAtuZXh0X2N1cnNvcgAAAAPoAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAAD0NlcnRpZmljYXRlUGFnZQAAAAACAAAAAAAAAAVpdGVtcwAAAAAAA+oAAAfQAAAAD0NlcnRpZmljYXRlRGF0YQAAAAAAAAAAC25leHRfY3Vyc29yAAAAA+gAAAAG",
+        "AAAAAgAAADRIb3cgYSBzZWxmIGNoZWNrLWluIHNlc3Npb24gYXV0aGVudGljYXRlcyBhdHRlbmRlZXMuAAAAAAAAAA9DaGVja0luVmVyaWZpZXIAAAAAAgAAAAEAAAFsUm90YXRpbmcgY29kZTogYChhbmNob3IsIHdpbmRvd19zZWNzKWAgd2hlcmUgYGFuY2hvcmAgaXMKYHNoYTI1Nl5uKHNlZWQpYC4gRHVyaW5nIHRoZSBga2AtdGggYHdpbmRvd19zZWNzYCBwZXJpb2Qgc2luY2UgdGhlCnNlc3Npb24gb3BlbmVkIChjb3VudGluZyBmcm9tIDEpIHRoZSBzdXBlcnZpc29yIHNob3dzIHRoZSAzMi1ieXRlCmNvZGUgYHNoYTI1Nl4obi1rKShzZWVkKWAsIHdoaWNoIGFkbWl0cyBldmVyeSBhdHRlbmRlZSBpbiB0aGUgcm9vbS4KQSBjb2RlIG9ubHkgcmV2ZWFscyBlYXJsaWVyIG9uZXM7IHRoZSBwcmV2aW91cyB3aW5kb3cncyBjb2RlIGlzCnN0aWxsIGFjY2VwdGVkIHRvIGFic29yYiBzdWJtaXNzaW9uIGRlbGF5LgAAAAxSb3RhdGluZ0NvZGUAAAACAAAD7gAAACAAAAAGAAAAAQAAADdlZDI1NTE5IHB1YmxpYyBrZXkgb2YgdGhlIHN1cGVydmlzb3IncyBjaGVjay1pbiBkZXZpY2UuAAAAAApTaWduaW5nS2V5AAAAAAABAAAD7gAAACA=",
+        "AAAAAQAAAAAAAAAAAAAADkNoZWNrSW5TZXNzaW9uAAAAAAAEAAAAOFVuaXF1ZSBwZXIgb3BlbmVkIHNlc3Npb247IGJvdW5kIGludG8gYGNoZWNraW5fbWVzc2FnZWAuAAAABW5vbmNlAAAAAAAABgAAAAAAAAAJb3BlbmVkX2F0AAAAAAAABgAAAAAAAAAJb3BlbmVkX2J5AAAAAAAAEwAAAAAAAAAIdmVyaWZpZXIAAAfQAAAAD0NoZWNrSW5WZXJpZmllcgA=",
+        "AAAAAQAAAEBPZmZsaW5lIGF0dGVuZGFuY2Ugdm91Y2hlciBzaWduZWQgYnkgYHN1cGVydmlzb3JgJ3Mgdm91Y2hlciBrZXkuAAAAAAAAAAdWb3VjaGVyAAAAAAUAAAAAAAAACGF0dGVuZGVlAAAAEwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAlpc3N1ZWRfYXQAAAAAAAAGAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAACnN1cGVydmlzb3IAAAAAABM=",
+        "AAAAAQAAADhOb24tdHJhbnNmZXJhYmxlIHByb29mIHRoYXQgYG93bmVyYCBhdHRlbmRlZCBgZXZlbnRfaWRgLgAAAAAAAAAPQ2VydGlmaWNhdGVEYXRhAAAAAAYAAAAAAAAADWR1cmF0aW9uX3NlY3MAAAAAAAAGAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAAlpc3N1ZWRfYXQAAAAAAAAGAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAADXJlZ2lzdGVyZWRfYXQAAAAAAAAG",
+        "AAAAAQAAAJ1FbWVyZ2VuY3ktc3RvcCBmbGFncy4gb3duZXItcnVsZXMgaGFzIGl0cyBvd24gZmxhZ3MgYW5kIHN0b3JhZ2U7IGVhY2gKY29udHJhY3QgaXMgcGF1c2VkIHNlcGFyYXRlbHkuIEVhY2ggZmxhZyBoYWx0cyBhIGdyb3VwIG9mCmVudHJ5cG9pbnRzOyBzZWUgYHNldF9wYXVzZWAuAAAAAAAAAAAAAAtQYXVzZVN0YXR1cwAAAAAFAAAAAAAAAAVhZG1pbgAAAAAAAAEAAAAAAAAABmNsYWltcwAAAAAAAQAAAAAAAAAOZXZlbnRfY3JlYXRpb24AAAAAAAEAAAAAAAAACGlzc3VhbmNlAAAAAQAAAAAAAAAMcmVnaXN0cmF0aW9uAAAAAQ==",
+        "AAAAAQAAADJBZG1pbiBoYW5kb3ZlciBhd2FpdGluZyBhY2NlcHRhbmNlIGJ5IGBuZXdfYWRtaW5gLgAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAACAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAluZXdfYWRtaW4AAAAAAAAT",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAALb3duZXJfcnVsZXMAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAPc2V0X293bmVyX3J1bGVzAAAAAAEAAAAAAAAACGNvbnRyYWN0AAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAPZ2V0X293bmVyX3J1bGVzAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAMY3JlYXRlX2V2ZW50AAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACHN0YXJ0X3RzAAAABgAAAAAAAAAGZW5kX3RzAAAAAAAGAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAD9DcmVhdGVzIGEgc2VyaWVzIHdob3NlIHNlc3Npb25zIGFyZSB0aGUgZ2l2ZW4gZXhwbGljaXQgd2luZG93cy4AAAAADWNyZWF0ZV9zZXJpZXMAAAAAAAADAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAhzZXNzaW9ucwAAA+oAAAfQAAAADVNlc3Npb25XaW5kb3cAAAAAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAALNDcmVhdGVzIGEgc2VyaWVzIG9mIGBjb3VudGAgc2Vzc2lvbnMgbGFzdGluZyBgZHVyYXRpb25fc2Vjc2AsIHRoZQpmaXJzdCBzdGFydGluZyBhdCBgZmlyc3Rfc3RhcnRfdHNgIGFuZCBlYWNoIGZvbGxvd2luZyBvbmUKYGludGVydmFsX3NlY3NgIGxhdGVyIChlLmcuIDYwNDgwMCBmb3Igd2Vla2x5IGNsYXNzZXMpLgAAAAAXY3JlYXRlX3JlY3VycmluZ19zZXJpZXMAAAAABgAAAAAAAAAEbmFtZQAAABAAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAOZmlyc3Rfc3RhcnRfdHMAAAAAAAYAAAAAAAAADWR1cmF0aW9uX3NlY3MAAAAAAAAGAAAAAAAAAA1pbnRlcnZhbF9zZWNzAAAAAAAABgAAAAAAAAAFY291bnQAAAAAAAAEAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAAAAAAAKZ2V0X3NlcmllcwAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABgAAAAEAAAPoAAAH0AAAAApTZXJpZXNEYXRhAAA=",
+        "AAAAAAAAAAAAAAAUbGlzdF9zZXJpZXNfc2Vzc2lvbnMAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAGAAAAAQAAA+kAAAPqAAAH0AAAAAxFdmVudFN1bW1hcnkAAAAD",
+        "AAAAAAAAAG9TZXNzaW9ucyBvZiB0aGUgc2VyaWVzIGBhdHRlbmRlZWAgY291bnRzIGFzIHByZXNlbnQgYXQsIGFnYWluc3QgdGhlCnNlc3Npb25zIGhlbGQgc28gZmFyIGFuZCB0aGUgdG90YWwgcGxhbm5lZC4AAAAAFWdldF9zZXJpZXNfYXR0ZW5kYW5jZQAAAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAYAAAAAAAAACGF0dGVuZGVlAAAAEwAAAAEAAAPpAAAH0AAAABBTZXJpZXNBdHRlbmRhbmNlAAAAAw==",
         "AAAAAAAAAAAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAA+gAAAfQAAAACUV2ZW50RGF0YQAAAA==",
-        "AAAAAAAAAAAAAAARcmVnaXN0ZXJfcHJlc2VuY2UAAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACGF0dGVuZGVlAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAMdXBkYXRlX2V2ZW50AAAAAwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAAAAAABG5hbWUAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAQcmVzY2hlZHVsZV9ldmVudAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAhzdGFydF90cwAAAAYAAAAAAAAABmVuZF90cwAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAG1PdmVycmlkZXMgaG93IGVhcmx5IGF0dGVuZGVlcyBtYXkgcmVnaXN0ZXIgYW5kIGhvdyBsb25nIGFmdGVyIHRoZQplbmQgcmVnaXN0cmF0aW9uIHN0YXlzIG9wZW4gZm9yIHRoaXMgZXZlbnQuAAAAAAAAEnNldF9jaGVja2luX3dpbmRvdwAAAAAABAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAAAAAAEmVhcmx5X2NoZWNraW5fc2VjcwAAAAAABgAAAAAAAAAPbGF0ZV9ncmFjZV9zZWNzAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEdTZXRzIHRoZSBtaW5pbXVtIGNoZWNrZWQtaW4gdGltZSBhbiBhdHRlbmRlZSBuZWVkcyB0byBjb3VudCBhcyBwcmVzZW50LgAAAAAQc2V0X21pbl9kdXJhdGlvbgAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAABFtaW5fZHVyYXRpb25fc2VjcwAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAANBTZXRzIHdoZXRoZXIgdGhlIGV2ZW50IG9ubHkgYWRtaXRzIGVucm9sbGVkIGF0dGVuZGVlcyBhbmQgaXRzCmNhcGFjaXR5ICgwID0gdW5saW1pdGVkKS4gU2VhdHMgZnJlZWQgYnkgYSBsYXJnZXIgY2FwYWNpdHkgZ28gdG8gdGhlCndhaXRsaXN0IGluIG9yZGVyLCB1cCB0byBvbmUgYm91bmRlZCBwYXNzOyBgcHJvbW90ZV93YWl0bGlzdGAgZmlsbHMKdGhlIHJlc3QuAAAAFXNldF9lbnJvbGxtZW50X3BvbGljeQAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAtpbnZpdGVfb25seQAAAAABAAAAAAAAAAxtYXhfY2FwYWNpdHkAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAO1Qcm9tb3RlcyB3YWl0bGlzdGVkIGF0dGVuZGVlcyBpbiBvcmRlciB3aGlsZSB0aGUgZXZlbnQgaGFzIGZyZWUgc2VhdHMsCmV4YW1pbmluZyBhdCBtb3N0IGBNQVhfUEFHRV9TQ0FOYCB3YWl0bGlzdCBzbG90cy4gQW55b25lIG1heSBjYWxsIGl0CnRvIGNvbnRpbnVlIGEgcHJvbW90aW9uIGEgYm91bmRlZCBwYXNzIGxlZnQgdW5maW5pc2hlZC4gUmV0dXJucyB0aGUKbnVtYmVyIG9mIGF0dGVuZGVlcyBwcm9tb3RlZC4AAAAAAAAQcHJvbW90ZV93YWl0bGlzdAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAIpBZGRzIGF0dGVuZGVlcyB0byB0aGUgZW5yb2xsbWVudCBsaXN0LiBPbmNlIGNhcGFjaXR5IGlzIHJlYWNoZWQgdGhlCnJlbWFpbmluZyBvbmVzIGFyZSB3YWl0bGlzdGVkLiBBbHJlYWR5IGVucm9sbGVkIGF0dGVuZGVlcyBhcmUgc2tpcHBlZC4AAAAAAAxlbnJvbGxfYmF0Y2gAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAJYXR0ZW5kZWVzAAAAAAAD6gAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADNTZWxmLWVucm9sbG1lbnQgb24gZXZlbnRzIHRoYXQgYXJlIG5vdCBpbnZpdGUtb25seS4AAAAAC3NlbGZfZW5yb2xsAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAA+kAAAfQAAAAEEVucm9sbG1lbnRTdGF0dXMAAAAD",
+        "AAAAAAAAAJZXaXRoZHJhd3MgYW4gZW5yb2xsbWVudCBvciB3YWl0bGlzdCBlbnRyeS4gQSBmcmVlZCBzZWF0IGdvZXMgdG8gdGhlCmZpcnN0IGF0dGVuZGVlIHN0aWxsIG9uIHRoZSB3YWl0bGlzdCwgdW5sZXNzIGEgbG93ZXJlZCBjYXBhY2l0eSBpcwpzdGlsbCBleGNlZWRlZC4AAAAAABN3aXRoZHJhd19lbnJvbGxtZW50AAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAOZ2V0X2Vucm9sbG1lbnQAAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAA+gAAAfQAAAAEEVucm9sbG1lbnRTdGF0dXM=",
+        "AAAAAAAAAAAAAAAOZW5yb2xsZWRfY291bnQAAAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAE",
+        "AAAAAAAAAC1XaW5kb3cgYXBwbGllZCB0byBldmVudHMgY3JlYXRlZCBmcm9tIG5vdyBvbi4AAAAAAAAac2V0X2RlZmF1bHRfY2hlY2tpbl93aW5kb3cAAAAAAAIAAAAAAAAAEmVhcmx5X2NoZWNraW5fc2VjcwAAAAAABgAAAAAAAAAPbGF0ZV9ncmFjZV9zZWNzAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADBSZXR1cm5zIGAoZWFybHlfY2hlY2tpbl9zZWNzLCBsYXRlX2dyYWNlX3NlY3MpYC4AAAAaZ2V0X2RlZmF1bHRfY2hlY2tpbl93aW5kb3cAAAAAAAAAAAABAAAD7QAAAAIAAAAGAAAABg==",
+        "AAAAAAAAAAAAAAANYWRkX29yZ2FuaXplcgAAAAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAlvcmdhbml6ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAQcmVtb3ZlX29yZ2FuaXplcgAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAlvcmdhbml6ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAMY2FuY2VsX2V2ZW50AAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIlFeHRlbmRzIHRoZSBUVEwgb2YgYW4gZXZlbnQgcmVjb3JkLCBpdHMgYXR0ZW5kZWUgY291bnRlcnMgYW5kIGNoZWNrLWluCnNlc3Npb24uIEF0dGVuZGVlIHJvd3MgYXJlIGJ1bXBlZCBzZXBhcmF0ZWx5IHZpYSBgYnVtcF9hdHRlbmRlZXNgLgAAAAAAAApidW1wX2V2ZW50AAAAAAABAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHtFeHRlbmRzIHRoZSBUVEwgb2YgdXAgdG8gYGxpbWl0YCBhdHRlbmRlZSByb3dzIG9mIGFuIGV2ZW50LCBzdGFydGluZwphdCBpbmRleCBgY3Vyc29yYCwgdG9nZXRoZXIgd2l0aCB0aGVpciBwcmVzZW5jZSBmbGFncy4AAAAADmJ1bXBfYXR0ZW5kZWVzAAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAARcmVnaXN0ZXJfcHJlc2VuY2UAAAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAALtSZWdpc3RlcnMgc2V2ZXJhbCBhdHRlbmRlZXMgYXQgb25jZS4gQWxyZWFkeSByZWdpc3RlcmVkIGF0dGVuZGVlcyBhcmUKc2tpcHBlZDsgYXR0ZW5kZWVzIHRoZSBlbnJvbGxtZW50IHBvbGljeSBkb2VzIG5vdCBhZG1pdCAobm90IGVucm9sbGVkCm9yIG92ZXIgY2FwYWNpdHkpIGFyZSBza2lwcGVkIHRvbyBhbmQgcmV0dXJuZWQuAAAAABdyZWdpc3Rlcl9wcmVzZW5jZV9iYXRjaAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAJYXR0ZW5kZWVzAAAAAAAD6gAAABMAAAABAAAD6QAAA+oAAAATAAAAAw==",
+        "AAAAAAAAAAAAAAARcmVnaXN0ZXJfY2hlY2tvdXQAAAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAGRDaGVja3Mgb3V0IGV2ZXJ5IGxpc3RlZCBhdHRlbmRlZSB0aGF0IGlzIHJlZ2lzdGVyZWQgYW5kIG5vdCB5ZXQKY2hlY2tlZCBvdXQ7IHRoZSBvdGhlcnMgYXJlIHNraXBwZWQuAAAAF3JlZ2lzdGVyX2NoZWNrb3V0X2JhdGNoAAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAlhdHRlbmRlZXMAAAAAAAPqAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAE5BdHRlbmRhbmNlIGR1cmF0aW9uIGluIHNlY29uZHMsIG9yIGBOb25lYCBpZiB0aGUgYXR0ZW5kZWUgaGFzIG5vdApjaGVja2VkIG91dC4AAAAAABdnZXRfYXR0ZW5kYW5jZV9kdXJhdGlvbgAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACGF0dGVuZGVlAAAAEwAAAAEAAAPoAAAABg==",
+        "AAAAAAAAAEpXaGV0aGVyIHRoZSBhdHRlbmRlZSBpcyByZWdpc3RlcmVkIGFuZCBtZWV0cyB0aGUgZXZlbnQncyBtaW5pbXVtCmR1cmF0aW9uLgAAAAAACmlzX3ByZXNlbnQAAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAH1PcGVucyAob3Igcm90YXRlcykgdGhlIHNlbGYgY2hlY2staW4gc2Vzc2lvbiBvZiBhbiBldmVudC4gQXR0ZW5kZWVzCnRoZW4gY2FsbCBgY2hlY2tfaW5gIHdpdGggYSBwcm9vZiBhY2NlcHRlZCBieSBgdmVyaWZpZXJgLgAAAAAAABRvcGVuX2NoZWNraW5fc2Vzc2lvbgAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAh2ZXJpZmllcgAAB9AAAAAPQ2hlY2tJblZlcmlmaWVyAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAVY2xvc2VfY2hlY2tpbl9zZXNzaW9uAAAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAATZ2V0X2NoZWNraW5fc2Vzc2lvbgAAAAABAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAD6AAAB9AAAAAOQ2hlY2tJblNlc3Npb24AAA==",
+        "AAAAAAAAAUZTZWxmIGNoZWNrLWluLiBXaXRoIGBSb3RhdGluZ0NvZGVgIHRoZSBwcm9vZiBpcyB0aGUgMzItYnl0ZSBjb2RlIG9mCnRoZSBjdXJyZW50IChvciBwcmV2aW91cykgd2luZG93OyB3aXRoIGBTaWduaW5nS2V5YCBpdCBpcyBhbiBlZDI1NTE5CnNpZ25hdHVyZSBvdmVyIGBjaGVja2luX21lc3NhZ2UoZXZlbnRfaWQsIGF0dGVuZGVlLCBub25jZSlgLCB1c2FibGUKb25jZSBwZXIgc2Vzc2lvbi4gQSBzaWduYXR1cmUgdGhhdCBkb2VzIG5vdCB2ZXJpZnkgYWJvcnRzIHRoZQppbnZvY2F0aW9uIGluIHRoZSBob3N0IGluc3RlYWQgb2YgcmV0dXJuaW5nIGBJbnZhbGlkUHJvb2ZgLgAAAAAACGNoZWNrX2luAAAAAwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhhdHRlbmRlZQAAABMAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAG5NZXNzYWdlIGEgc2Vzc2lvbiBzaWduaW5nIGtleSBtdXN0IHNpZ24gdG8gYWRtaXQgYGF0dGVuZGVlYDsgYG5vbmNlYAppcyB0aGUgb25lIG9mIHRoZSBjdXJyZW50bHkgb3BlbiBzZXNzaW9uLgAAAAAAD2NoZWNraW5fbWVzc2FnZQAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACGF0dGVuZGVlAAAAEwAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAQAAAA4=",
+        "AAAAAAAAAEFSZWdpc3RlcnMgdGhlIGVkMjU1MTkga2V5IGBvcGVyYXRvcmAgc2lnbnMgb2ZmbGluZSB2b3VjaGVycyB3aXRoLgAAAAAAAA9zZXRfdm91Y2hlcl9rZXkAAAAAAgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAScmVtb3ZlX3ZvdWNoZXJfa2V5AAAAAAABAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAPZ2V0X3ZvdWNoZXJfa2V5AAAAAAEAAAAAAAAACnN1cGVydmlzb3IAAAAAABMAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAASJSZWRlZW1zIGEgc3VwZXJ2aXNvci1zaWduZWQgYXR0ZW5kYW5jZSB2b3VjaGVyLiBBbnlvbmUgbWF5IHN1Ym1pdCBpdDsKdGhlIGF0dGVuZGVlIGlzIHJlZ2lzdGVyZWQgYXMgb2YgYHZvdWNoZXIuaXNzdWVkX2F0YC4gQSB2b3VjaGVyCmlzc3VlZCBpbiB0aGUgZnV0dXJlIGlzIGBJbnZhbGlkVm91Y2hlcmA7IGEgc2lnbmF0dXJlIHRoYXQgZG9lcyBub3QKdmVyaWZ5IGFib3J0cyB0aGUgaW52b2NhdGlvbiBpbiB0aGUgaG9zdCwgc28gZXZlcnkgdHlwZWQgY2hlY2sgcnVucwpiZWZvcmUgdmVyaWZpY2F0aW9uLgAAAAAADnJlZGVlbV92b3VjaGVyAAAAAAACAAAAAAAAAAd2b3VjaGVyAAAAB9AAAAAHVm91Y2hlcgAAAAAAAAAACXNpZ25hdHVyZQAAAAAAA+4AAABAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAC1NZXNzYWdlIGEgc3VwZXJ2aXNvcidzIHZvdWNoZXIga2V5IG11c3Qgc2lnbi4AAAAAAAAPdm91Y2hlcl9tZXNzYWdlAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAAAAAAlpc3N1ZWRfYXQAAAAAAAAGAAAAAAAAAAVub25jZQAAAAAAAAYAAAABAAAADg==",
         "AAAAAAAAAAAAAAAMaGFzX3ByZXNlbmNlAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhhdHRlbmRlZQAAABMAAAABAAAAAQ==",
-        "AAAAAAAAAAAAAAAObGlzdF9hdHRlbmRlZXMAAAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGY3Vyc29yAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAMQXR0ZW5kZWVJbmZv",
-        "AAAAAAAAAAAAAAAPcmVtb3ZlX3ByZXNlbmNlAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAA==",
-        "AAAAAAAAAAAAAAALbGlzdF9ldmVudHMAAAAAAgAAAAAAAAAIc3RhcnRfaWQAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAMRXZlbnRTdW1tYXJ5",
-        "AAAAAAAAAAAAAAANbGlzdF91cGNvbWluZwAAAAAAAAAAAAABAAAD6gAAB9AAAAAMRXZlbnRTdW1tYXJ5",
-        "AAAAAAAAAAAAAAALbGlzdF9jbG9zZWQAAAAAAgAAAAAAAAAGY3Vyc29yAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAMRXZlbnRTdW1tYXJ5",
-        "AAAAAAAAAAAAAAAOdHJhbnNmZXJfYWRtaW4AAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAG9BdHRlbmRlZXMgaW4gcmVnaXN0cmF0aW9uIG9yZGVyIHN0YXJ0aW5nIGF0IGluZGV4IGBjdXJzb3JgLiBXaXRoCmBmaWx0ZXJfYWN0aXZlYCByZW1vdmVkIGF0dGVuZGVlcyBhcmUgc2tpcHBlZC4AAAAADmxpc3RfYXR0ZW5kZWVzAAAAAAAEAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAAAAAA1maWx0ZXJfYWN0aXZlAAAAAAAAAQAAAAEAAAfQAAAADEF0dGVuZGVlUGFnZQ==",
+        "AAAAAAAAADdOdW1iZXIgb2YgcmVnaXN0ZXJlZCBhdHRlbmRlZXMsIGV4Y2x1ZGluZyByZW1vdmVkIG9uZXMuAAAAABZhY3RpdmVfYXR0ZW5kZWVzX2NvdW50AAAAAAABAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAPcmVtb3ZlX3ByZXNlbmNlAAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAhhdHRlbmRlZQAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIJFdmVudHMgYGF0dGVuZGVlYCBpcyBjdXJyZW50bHkgcmVnaXN0ZXJlZCBhdC4gUmVtb3ZhbHMgbW92ZSB0aGUgbGFzdAplbnRyeSBpbnRvIHRoZSBmcmVlZCBzbG90LCBzbyB0aGUgb3JkZXIgaXMgbm90IGNocm9ub2xvZ2ljYWwuAAAAAAAYbGlzdF9ldmVudHNfZm9yX2F0dGVuZGVlAAAAAwAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAAAAAAZjdXJzb3IAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACUV2ZW50UGFnZQAAAA==",
+        "AAAAAAAAAAAAAAAQYXR0ZW5kYW5jZV9jb3VudAAAAAEAAAAAAAAACGF0dGVuZGVlAAAAEwAAAAEAAAAG",
+        "AAAAAAAAADBFdmVudHMgYnkgYXNjZW5kaW5nIGlkLCBzdGFydGluZyBhdCBpZCBgY3Vyc29yYC4AAAALbGlzdF9ldmVudHMAAAAAAgAAAAAAAAAGY3Vyc29yAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAH0AAAAAlFdmVudFBhZ2UAAAA=",
+        "AAAAAAAAAAAAAAAWbGlzdF9ldmVudHNfYnlfY3JlYXRvcgAAAAAAAwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAZjdXJzb3IAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACUV2ZW50UGFnZQAAAA==",
+        "AAAAAAAAACZFdmVudHMgbm90IHlldCBzdGFydGVkLCBzb29uZXN0IGZpcnN0LgAAAAAADWxpc3RfdXBjb21pbmcAAAAAAAACAAAAAAAAAAZjdXJzb3IAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACUV2ZW50UGFnZQAAAA==",
+        "AAAAAAAAADlFdmVudHMgc3RhcnRpbmcgd2l0aGluIGBbZnJvbV90cywgdG9fdHNdYCwgYnkgc3RhcnQgdGltZS4AAAAAAAANbGlzdF9pbl9yYW5nZQAAAAAAAAQAAAAAAAAAB2Zyb21fdHMAAAAABgAAAAAAAAAFdG9fdHMAAAAAAAAGAAAAAAAAAAZjdXJzb3IAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACUV2ZW50UGFnZQAAAA==",
+        "AAAAAAAAAK5PcGVuIGV2ZW50cyAoYHN0YXJ0X3RzIDw9IG5vdyA8PSBlbmRfdHMgKyBsYXRlX2dyYWNlX3NlY3NgKSwgc28KYXR0ZW5kZWVzIGNhbiBzdGlsbCByZWdpc3RlciBkdXJpbmcgdGhlIGdyYWNlIHBlcmlvZDogZmlyc3QgdGhvc2UKZnJvbSB0aGUgcmVjZW50IGRheSBpbmRleCwgdGhlbiBsb25nIGV2ZW50cy4AAAAAAAxsaXN0X29uZ29pbmcAAAACAAAAAAAAAAZjdXJzb3IAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACUV2ZW50UGFnZQAAAA==",
+        "AAAAAAAAAGVDbG9zZWQgZXZlbnRzIGZyb20gdGhlIG5ld2VzdCBpZCBkb3duLCBzdGFydGluZyBhdCBpZCBgY3Vyc29yYAooMCBzdGFydHMgZnJvbSB0aGUgbW9zdCByZWNlbnQgZXZlbnQpLgAAAAAAAAtsaXN0X2Nsb3NlZAAAAAACAAAAAAAAAAZjdXJzb3IAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACUV2ZW50UGFnZQAAAA==",
+        "AAAAAAAAAAAAAAAYc2V0X2NlcnRpZmljYXRlX2Jhc2VfdXJpAAAAAQAAAAAAAAAHbmV3X3VyaQAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAIZNaW50cyB0aGUgc291bGJvdW5kIGF0dGVuZGFuY2UgY2VydGlmaWNhdGUgb2YgYSBjbG9zZWQgZXZlbnQgdG8gb25lIG9mCml0cyBhY3RpdmUgYXR0ZW5kZWVzLiBDZXJ0aWZpY2F0ZXMgaGF2ZSBubyB0cmFuc2ZlciBlbnRyeXBvaW50LgAAAAAAEWNsYWltX2NlcnRpZmljYXRlAAAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhhdHRlbmRlZQAAABMAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAAAAAAAPZ2V0X2NlcnRpZmljYXRlAAAAAAEAAAAAAAAAB2NlcnRfaWQAAAAABgAAAAEAAAPoAAAH0AAAAA9DZXJ0aWZpY2F0ZURhdGEA",
+        "AAAAAAAAAAAAAAAPY2VydGlmaWNhdGVfZm9yAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIYXR0ZW5kZWUAAAATAAAAAQAAA+gAAAAG",
+        "AAAAAAAAAAAAAAARY2VydGlmaWNhdGVfY291bnQAAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAABg==",
+        "AAAAAAAAAAAAAAARbGlzdF9jZXJ0aWZpY2F0ZXMAAAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAB9AAAAAPQ2VydGlmaWNhdGVQYWdlAA==",
+        "AAAAAAAAAFdNZXRhZGF0YSBVUkkgb2YgYSBjZXJ0aWZpY2F0ZTogdGhlIGNlcnRpZmljYXRlIGJhc2UgVVJJIGZvbGxvd2VkIGJ5CnRoZSBjZXJ0aWZpY2F0ZSBpZC4AAAAAD2NlcnRpZmljYXRlX3VyaQAAAAABAAAAAAAAAAdjZXJ0X2lkAAAAAAYAAAABAAAD6QAAABAAAAAD",
+        "AAAAAAAAABhSYWlzZXMgZXZlcnkgcGF1c2UgZmxhZy4AAAAFcGF1c2UAAAAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAddTZXRzIGVhY2ggcGF1c2UgZmxhZyBpbmRpdmlkdWFsbHk6Ci0gYGV2ZW50X2NyZWF0aW9uYDogY3JlYXRpbmcsIGVkaXRpbmcsIHJlc2NoZWR1bGluZywgY29uZmlndXJpbmcgYW5kCmNhbmNlbGxpbmcgZXZlbnRzIGFuZCBzZXJpZXMsIGluY2x1ZGluZyB0aGVpciBvcmdhbml6ZXJzLgotIGByZWdpc3RyYXRpb25gOiBlbnJvbGxtZW50LCBwcmVzZW5jZSBhbmQgaXRzIHJlbW92YWwsIGNoZWNrb3V0LApjaGVjay1pbiBhbmQgdm91Y2hlcnMuCi0gYGlzc3VhbmNlYDogb3BlbmluZyBhbmQgY2xvc2luZyBjaGVjay1pbiBzZXNzaW9ucyBhbmQgcmVnaXN0ZXJpbmcKYW5kIHJlbW92aW5nIHZvdWNoZXIga2V5cy4KLSBgY2xhaW1zYDogY2VydGlmaWNhdGUgY2xhaW1zLgotIGBhZG1pbmA6IGBwcm9wb3NlX2FkbWluYCBhbmQgYGFjY2VwdF9hZG1pbmAuCgpDYW5jZWxsaW5nIGFuIGFkbWluIHByb3Bvc2FsIHN0YXlzIGF2YWlsYWJsZS4AAAAACXNldF9wYXVzZQAAAAAAAAEAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtQYXVzZVN0YXR1cwAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAMcGF1c2Vfc3RhdHVzAAAAAAAAAAEAAAfQAAAAC1BhdXNlU3RhdHVzAA==",
+        "AAAAAAAAAGxGaXJzdCBzdGVwIG9mIGFuIGFkbWluIGhhbmRvdmVyLiBOb3RoaW5nIGNoYW5nZXMgdW50aWwgYG5ld19hZG1pbmAKY2FsbHMgYGFjY2VwdF9hZG1pbmAgYmVmb3JlIGBleHBpcmVzX2F0YC4AAAANcHJvcG9zZV9hZG1pbgAAAAAAAAIAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAVY2FuY2VsX2FkbWluX3Byb3Bvc2FsAAAAAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAG5SZXR1cm5zIHRoZSBvdXRzdGFuZGluZyBwcm9wb3NhbCwgaWYgYW55LiBBbiBleHBpcmVkIHByb3Bvc2FsIHN0YXlzCnZpc2libGUgdW50aWwgaXQgaXMgY2FuY2VsbGVkIG9yIHJlcGxhY2VkLgAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAH0AAAAAxQZW5kaW5nQWRtaW4=",
       ]),
       options,
     );
   }
   public readonly fromJSON = {
-    set_supervisor: this.txFromJSON<null>,
-    create_event: this.txFromJSON<u64>,
+    set_owner_rules: this.txFromJSON<Result<void>>,
+    get_owner_rules: this.txFromJSON<Option<string>>,
+    create_event: this.txFromJSON<Result<u64>>,
+    create_series: this.txFromJSON<Result<u64>>,
+    create_recurring_series: this.txFromJSON<Result<u64>>,
+    get_series: this.txFromJSON<Option<SeriesData>>,
+    list_series_sessions: this.txFromJSON<Result<Array<EventSummary>>>,
+    get_series_attendance: this.txFromJSON<Result<SeriesAttendance>>,
     get_event: this.txFromJSON<Option<EventData>>,
-    register_presence: this.txFromJSON<null>,
+    update_event: this.txFromJSON<Result<void>>,
+    reschedule_event: this.txFromJSON<Result<void>>,
+    set_checkin_window: this.txFromJSON<Result<void>>,
+    set_min_duration: this.txFromJSON<Result<void>>,
+    set_enrollment_policy: this.txFromJSON<Result<void>>,
+    promote_waitlist: this.txFromJSON<Result<u32>>,
+    enroll_batch: this.txFromJSON<Result<void>>,
+    self_enroll: this.txFromJSON<Result<EnrollmentStatus>>,
+    withdraw_enrollment: this.txFromJSON<Result<void>>,
+    get_enrollment: this.txFromJSON<Option<EnrollmentStatus>>,
+    enrolled_count: this.txFromJSON<u32>,
+    set_default_checkin_window: this.txFromJSON<Result<void>>,
+    get_default_checkin_window: this.txFromJSON<readonly [u64, u64]>,
+    add_organizer: this.txFromJSON<Result<void>>,
+    remove_organizer: this.txFromJSON<Result<void>>,
+    cancel_event: this.txFromJSON<Result<void>>,
+    bump_event: this.txFromJSON<Result<void>>,
+    bump_attendees: this.txFromJSON<Result<void>>,
+    register_presence: this.txFromJSON<Result<void>>,
+    register_presence_batch: this.txFromJSON<Result<Array<string>>>,
+    register_checkout: this.txFromJSON<Result<void>>,
+    register_checkout_batch: this.txFromJSON<Result<void>>,
+    get_attendance_duration: this.txFromJSON<Option<u64>>,
+    is_present: this.txFromJSON<boolean>,
+    open_checkin_session: this.txFromJSON<Result<void>>,
+    close_checkin_session: this.txFromJSON<Result<void>>,
+    get_checkin_session: this.txFromJSON<Option<CheckInSession>>,
+    check_in: this.txFromJSON<Result<void>>,
+    checkin_message: this.txFromJSON<Buffer>,
+    set_voucher_key: this.txFromJSON<Result<void>>,
+    remove_voucher_key: this.txFromJSON<Result<void>>,
+    get_voucher_key: this.txFromJSON<Option<Buffer>>,
+    redeem_voucher: this.txFromJSON<Result<void>>,
+    voucher_message: this.txFromJSON<Buffer>,
     has_presence: this.txFromJSON<boolean>,
-    list_attendees: this.txFromJSON<Array<AttendeeInfo>>,
-    remove_presence: this.txFromJSON<null>,
-    list_events: this.txFromJSON<Array<EventSummary>>,
-    list_upcoming: this.txFromJSON<Array<EventSummary>>,
-    list_closed: this.txFromJSON<Array<EventSummary>>,
-    transfer_admin: this.txFromJSON<null>,
+    list_attendees: this.txFromJSON<AttendeePage>,
+    active_attendees_count: this.txFromJSON<u64>,
+    remove_presence: this.txFromJSON<Result<void>>,
+    list_events_for_attendee: this.txFromJSON<EventPage>,
+    attendance_count: this.txFromJSON<u64>,
+    list_events: this.txFromJSON<EventPage>,
+    list_events_by_creator: this.txFromJSON<EventPage>,
+    list_upcoming: this.txFromJSON<EventPage>,
+    list_in_range: this.txFromJSON<EventPage>,
+    list_ongoing: this.txFromJSON<EventPage>,
+    list_closed: this.txFromJSON<EventPage>,
+    set_certificate_base_uri: this.txFromJSON<Result<void>>,
+    claim_certificate: this.txFromJSON<Result<u64>>,
+    get_certificate: this.txFromJSON<Option<CertificateData>>,
+    certificate_for: this.txFromJSON<Option<u64>>,
+    certificate_count: this.txFromJSON<u64>,
+    list_certificates: this.txFromJSON<CertificatePage>,
+    certificate_uri: this.txFromJSON<Result<string>>,
+    pause: this.txFromJSON<Result<void>>,
+    unpause: this.txFromJSON<Result<void>>,
+    set_pause: this.txFromJSON<Result<void>>,
+    pause_status: this.txFromJSON<PauseStatus>,
+    propose_admin: this.txFromJSON<Result<void>>,
+    accept_admin: this.txFromJSON<Result<void>>,
+    cancel_admin_proposal: this.txFromJSON<Result<void>>,
+    get_admin: this.txFromJSON<Result<string>>,
+    get_pending_admin: this.txFromJSON<Option<PendingAdmin>>,
   };
 }
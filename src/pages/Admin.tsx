@@ -1,4 +1,4 @@
-import { useState } from "react";
+import { useEffect, useState } from "react";
 import { Button, Input, Layout, Text } from "@stellar/design-system";
 import ownerRules from "../contracts/owner_rules";
 import { useWallet } from "../hooks/useWallet";
@@ -6,8 +6,11 @@ import { useRoles } from "../hooks/useRoles";
 import { useNotification } from "../hooks/useNotification";
 import ConfirmationModal from "../components/ConfirmationModal";
 
+// Prazo para o novo admin aceitar a transferência
+const ADMIN_PROPOSAL_TTL_SECS = 7 * 86400;
+
 const Admin: React.FC = () => {
-  const { address } = useWallet();
+  const { address, signTransaction } = useWallet();
   const { isAdmin, ready } = useRoles();
   const { addNotification } = useNotification();
   const [baseUri, setBaseUri] = useState("");
@@ -16,6 +19,7 @@ const Admin: React.FC = () => {
   const [revokeAddr, setRevokeAddr] = useState("");
   const [revokeRole, setRevokeRole] = useState<number>(3);
   const [msg, setMsg] = useState("");
+  const [pendingAdmin, setPendingAdmin] = useState<string | null>(null);
   const [showConfirmation, setShowConfirmation] = useState(false);
   const [confirmationData, setConfirmationData] = useState({
     title: '',
@@ -34,6 +38,25 @@ const Admin: React.FC = () => {
     }
   };
 
+  useEffect(() => {
+    void (async () => {
+      try {
+        const tx = await ownerRules.get_pending_admin();
+        const pending: any = tx.result;
+        setPendingAdmin(pending?.new_admin ?? null);
+      } catch {
+        setPendingAdmin(null);
+      }
+    })();
+  }, [address]);
+
+  const acceptAdmin = () => void call(async () => {
+    const tx = await ownerRules.accept_admin({ publicKey: address } as any);
+    await (tx as any).signAndSend({ signTransaction });
+    setPendingAdmin(null);
+    addNotification("Admin aceito", "success");
+  });
+
   const showConfirmDialog = (title: string, message: string, onConfirm: () => void, type: 'danger' | 'warning' | 'info' = 'info') => {
     setConfirmationData({ title, message, onConfirm, type });
     setShowConfirmation(true);
@@ -49,6 +72,19 @@ const Admin: React.FC = () => {
     );
   }
   if (ready && !isAdmin) {
+    if (pendingAdmin === address) {
+      return (
+        <Layout.Content>
+          <Layout.Inset>
+            <Text as="p" size="md">Você foi indicado como novo administrador.</Text>
+            <Button onClick={acceptAdmin} variant="primary" size="md" className="primary-action">
+              Aceitar Admin
+            </Button>
+            {msg && <Text as="p" size="md">{msg}</Text>}
+          </Layout.Inset>
+        </Layout.Content>
+      );
+    }
     return (
       <Layout.Content>
         <Layout.Inset>
@@ -168,14 +204,20 @@ const Admin: React.FC = () => {
                   />
                 </div>
                 <Button 
-                  onClick={() => void call(() => ownerRules.transfer_admin({ new_admin: newAdmin }))} 
+                  onClick={() => void call(() => ownerRules.propose_admin({
+                    new_admin: newAdmin,
+                    expires_at: BigInt(Math.floor(Date.now() / 1000) + ADMIN_PROPOSAL_TTL_SECS),
+                  }))} 
                   variant="primary" 
                   size="md" 
                   className="primary-action"
                 >
                   <span>👑</span>
-                  Transferir Admin
+                  Propor Novo Admin
                 </Button>
+                {pendingAdmin && (
+                  <Text as="p" size="sm">Aguardando aceite de {pendingAdmin}</Text>
+                )}
               </div>
             </div>
             
@@ -17,6 +17,27 @@ type EventItem = {
   status: string;
 };
 
+// Caps how many pages of a listing one refresh walks through.
+const MAX_LISTING_PAGES = 10;
+
+const fetchAllPages = async (
+  method: "list_ongoing" | "list_upcoming",
+): Promise<any[]> => {
+  const items: any[] = [];
+  let cursor = 0;
+  for (let i = 0; i < MAX_LISTING_PAGES; i++) {
+    const r = await (presenceEvents as any)[method]({
+      cursor: BigInt(cursor),
+      limit: 20,
+    });
+    const page = (r as any)?.result;
+    items.push(...((page?.items as any[]) || []));
+    if (page?.next_cursor == null) break;
+    cursor = Number(page.next_cursor);
+  }
+  return items;
+};
+
 const Meetings: React.FC = () => {
   const { address, signTransaction } = useWallet();
   const { isSupervisor } = useRoles();
@@ -39,6 +60,8 @@ const Meetings: React.FC = () => {
   const [closedList, setClosedList] = useState<EventItem[]>([]);
   const [closedLoading, setClosedLoading] = useState<boolean>(false);
   const [closedCursor, setClosedCursor] = useState<number>(0);
+  const [closedPrev, setClosedPrev] = useState<number[]>([]);
+  const [closedNext, setClosedNext] = useState<number | null>(null);
   // sequence not needed when using signAndSend via contract client
 
   const toEpoch = (dt: string) => Math.floor(new Date(dt).getTime() / 1000);
@@ -61,16 +84,6 @@ const Meetings: React.FC = () => {
       (presenceEvents as any).options.contractId = currentContractId;
       setEventContractId(currentContractId);
 
-      try {
-        const txSup = await (presenceEvents as any).set_supervisor(
-          { user: address, status: true },
-          { publicKey: address },
-        );
-        log("info", "Solicitando assinatura para ativar supervisor");
-        await (txSup as any).signAndSend({ signTransaction });
-      } catch {
-        // ignore if already supervisor or unauthorized; create_event will validate
-      }
       const tx = await (presenceEvents as any).create_event(
         {
           name: evtName,
@@ -132,14 +145,17 @@ const Meetings: React.FC = () => {
 
     (presenceEvents as any).options.contractId = currentContractId;
     setEventContractId(currentContractId);
-    const r = await (presenceEvents as any).list_upcoming();
-    const arr: any[] = ((r as any)?.result as any[]) || [];
+    // Ongoing events (including the late grace period) come first, then
+    // the ones not started yet.
+    const arr: any[] = [
+      ...(await fetchAllPages("list_ongoing")),
+      ...(await fetchAllPages("list_upcoming")),
+    ];
     const now = BigInt(Math.floor(Date.now() / 1000));
     const out: EventItem[] = arr.map((it: any) => {
       const start = BigInt(it.start_ts);
       const end = BigInt(it.end_ts);
-      const status =
-        now < start ? "Agendada" : now > end ? "Encerrada" : "Ativa";
+      const status = now < start ? "Agendada" : "Ativa";
       return {
         id: Number(it.id),
         name: String(it.name),
@@ -153,7 +169,7 @@ const Meetings: React.FC = () => {
     logRef.current("success", `Próximas reuniões carregadas: ${out.length}`);
   }, []);
 
-  const refreshClosed = useCallback(async () => {
+  const refreshClosed = useCallback(async (cursor: number = closedCursor) => {
     setClosedLoading(true);
     log("info", "Buscando reuniões encerradas");
     const currentContractId = await getEventContractId();
@@ -169,10 +185,12 @@ const Meetings: React.FC = () => {
     (presenceEvents as any).options.contractId = currentContractId;
     setEventContractId(currentContractId);
     const r = await (presenceEvents as any).list_closed({
-      cursor: BigInt(closedCursor),
+      cursor: BigInt(cursor),
       limit: 10,
     });
-    const arr: any[] = ((r as any)?.result as any[]) || [];
+    const page = (r as any)?.result;
+    const arr: any[] = (page?.items as any[]) || [];
+    setClosedNext(page?.next_cursor != null ? Number(page.next_cursor) : null);
     const now = BigInt(Math.floor(Date.now() / 1000));
     const out: EventItem[] = arr.map((it: any) => {
       const start = BigInt(it.start_ts);
@@ -377,10 +395,12 @@ const Meetings: React.FC = () => {
                 variant="secondary"
                 size="sm"
                 onClick={() => {
-                  setClosedCursor(Math.max(0, closedCursor - 10));
-                  void refreshClosed();
+                  const prev = closedPrev[closedPrev.length - 1] ?? 0;
+                  setClosedPrev(closedPrev.slice(0, -1));
+                  setClosedCursor(prev);
+                  void refreshClosed(prev);
                 }}
-                disabled={closedLoading || closedCursor === 0}
+                disabled={closedLoading || closedPrev.length === 0}
               >
                 Anterior
               </Button>
@@ -388,10 +408,12 @@ const Meetings: React.FC = () => {
                 variant="secondary"
                 size="sm"
                 onClick={() => {
-                  setClosedCursor(closedCursor + 10);
-                  void refreshClosed();
+                  if (closedNext == null) return;
+                  setClosedPrev([...closedPrev, closedCursor]);
+                  setClosedCursor(closedNext);
+                  void refreshClosed(closedNext);
                 }}
-                disabled={closedLoading}
+                disabled={closedLoading || closedNext == null}
               >
                 Próxima
               </Button>
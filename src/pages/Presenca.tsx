@@ -28,11 +28,9 @@ type AttendeeRecord = {
 };
 
 const extractEvents = (payload: any): any[] => {
-  if (Array.isArray(payload)) {
-    return payload;
-  }
-  if (payload?.result && Array.isArray(payload.result)) {
-    return payload.result;
+  const page = payload?.result ?? payload;
+  if (page && Array.isArray(page.items)) {
+    return page.items;
   }
   return [];
 };
@@ -107,17 +105,25 @@ const Presenca: React.FC = () => {
       (presenceEvents as any).options.contractId = contractId;
       setContractReady(true);
 
-      const upcomingResp = await (presenceEvents as any).list_upcoming();
+      const ongoingResp = await (presenceEvents as any).list_ongoing({
+        cursor: BigInt(0),
+        limit: 20,
+      });
+      const upcomingResp = await (presenceEvents as any).list_upcoming({
+        cursor: BigInt(0),
+        limit: 20,
+      });
       const closedResp = await (presenceEvents as any).list_closed({
         cursor: BigInt(0),
         limit: 20,
       });
 
+      const ongoing = normalizeEvents(extractEvents(ongoingResp));
       const upcoming = normalizeEvents(extractEvents(upcomingResp));
       const closed = normalizeEvents(extractEvents(closedResp));
 
       const map = new Map<number, MeetingRecord>();
-      [...upcoming, ...closed].forEach((event) => {
+      [...ongoing, ...upcoming, ...closed].forEach((event) => {
         map.set(event.id, event);
       });
 
@@ -166,27 +172,22 @@ const Presenca: React.FC = () => {
           event_id: BigInt(meetingId),
           cursor: BigInt(cursor),
           limit: 25,
+          filter_active: true,
         });
-        const raw = (resp as any)?.result ?? resp ?? [];
-        const data: any[] = Array.isArray(raw) ? raw : [];
-
-        if (!Array.isArray(data) || data.length === 0) {
-          break;
-        }
+        const page = (resp as any)?.result ?? resp;
+        const data: any[] = Array.isArray(page?.items) ? page.items : [];
+
+        data.forEach((entry) =>
+          collected.push({
+            address: String(entry.address),
+            registeredAt: BigInt(entry.registered_at ?? 0),
+          }),
+        );
 
-        data
-          .filter((entry) => entry?.active)
-          .forEach((entry) =>
-            collected.push({
-              address: String(entry.address),
-              registeredAt: BigInt(entry.registered_at ?? 0),
-            }),
-          );
-
-        cursor += data.length;
-        if (data.length < 25) {
+        if (page?.next_cursor == null) {
           break;
         }
+        cursor = Number(page.next_cursor);
       }
 
       setAttendees(collected);
@@ -244,8 +244,16 @@ const Presence: React.FC = () => {
         },
         { publicKey: address },
       );
-      await (tx as any).signAndSend({ signTransaction });
-      addNotification("Presenças registradas", "success");
+      const sent = await (tx as any).signAndSend({ signTransaction });
+      const rejected: string[] = sent?.result ?? [];
+      if (rejected.length > 0) {
+        addNotification(
+          `${rejected.length} participante(s) não admitido(s) pela política de inscrição`,
+          "warning",
+        );
+      } else {
+        addNotification("Presenças registradas", "success");
+      }
       setScanList([]);
       await loadAttendees(0, true);
     } catch (e: any) {
@@ -401,26 +409,25 @@ const Presence: React.FC = () => {
         event_id: BigInt(eid),
         cursor: BigInt(cursor),
         limit: 10,
+        filter_active: true, // Somente participantes ativos
       });
-      const attendeesData = resp?.result ?? resp;
+      const page = resp?.result ?? resp;
 
-      if (attendeesData && Array.isArray(attendeesData)) {
-        const newAttendees: Attendee[] = attendeesData
-          .filter((attendee: any) => attendee.active) // Somente participantes ativos
-          .map((attendee: any) => ({
-            address: attendee.address,
-            registeredAt: BigInt(attendee.registered_at),
-          }));
+      if (page && Array.isArray(page.items)) {
+        const newAttendees: Attendee[] = page.items.map((attendee: any) => ({
+          address: attendee.address,
+          registeredAt: BigInt(attendee.registered_at),
+        }));
 
         if (reset) {
           setAttendees(newAttendees);
-          setAttendeesCursor(newAttendees.length);
         } else {
           setAttendees((prev) => [...prev, ...newAttendees]);
-          setAttendeesCursor((prev) => prev + newAttendees.length);
         }
 
-        setHasMoreAttendees(newAttendees.length === 10);
+        const next = page.next_cursor;
+        setAttendeesCursor(next != null ? Number(next) : 0);
+        setHasMoreAttendees(next != null);
         log("success", `Participantes carregados: ${newAttendees.length}`);
       } else {
         setHasMoreAttendees(false);
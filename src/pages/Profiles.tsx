@@ -14,6 +14,7 @@ const Profiles: React.FC = () => {
   const { log } = useOpLog();
 
   const [recipient, setRecipient] = useState("");
+  const [expiresLocal, setExpiresLocal] = useState<string>("");
   const [roleToGenerate, setRoleToGenerate] = useState<"2" | "3">("3");
   const [claimLink, setClaimLink] = useState<string>("");
 
@@ -31,6 +32,10 @@ const Profiles: React.FC = () => {
   const gen = async () => {
     setMsg("");
     setClaimLink("");
+    // Vazio usa a validade padrão do contrato
+    const expires_at = expiresLocal
+      ? BigInt(Math.floor(new Date(expiresLocal).getTime() / 1000))
+      : undefined;
     try {
       log("info", "Gerando claim");
       if (roleToGenerate === "2") {
@@ -39,7 +44,7 @@ const Profiles: React.FC = () => {
           return;
         }
         const tx = await (ownerRules as any).generate_supervisor_claim_link(
-          { recipient },
+          { recipient, expires_at },
           { publicKey: address },
         );
         log("info", "Solicitando assinatura");
@@ -52,7 +57,7 @@ const Profiles: React.FC = () => {
         addNotification("Claim para Supervisor gerado", "success");
       } else {
         const tx = await (ownerRules as any).generate_associate_claim_link(
-          { recipient, operator: address! },
+          { recipient, operator: address!, expires_at },
           { publicKey: address },
         );
         log("info", "Solicitando assinatura");
@@ -175,6 +180,18 @@ const Profiles: React.FC = () => {
                   />
                 </div>
 
+                <div className="input-group">
+                  <label className="input-label">Expira em (opcional)</label>
+                  <Input
+                    id="expires"
+                    type="datetime-local"
+                    value={expiresLocal}
+                    onChange={(e) => setExpiresLocal(e.target.value)}
+                    fieldSize="md"
+                    className="futuristic-input"
+                  />
+                </div>
+
                 <div className="radio-group">
                   <label className="input-label">Tipo de Credencial</label>
                   <label className="futuristic-radio">
@@ -25,6 +25,7 @@ const Settings: React.FC = () => {
   const [initAdmin, setInitAdmin] = useState("");
   const [msg, setMsg] = useState("");
   const [supAddr, setSupAddr] = useState("");
+  const [supClaimLink, setSupClaimLink] = useState("");
   const [peCid, setPeCid] = useState("");
   const [currentContract, setCurrentContract] = useState("");
   const [onChainEventCtr, setOnChainEventCtr] = useState("");
@@ -121,20 +122,28 @@ const Settings: React.FC = () => {
           <Button
             onClick={() =>
               void call(async () => {
-                log("info", `Transferindo admin para ${newAdmin}`);
-                const tx = await ownerRules.transfer_admin(
-                  { new_admin: newAdmin },
+                log("info", `Propondo ${newAdmin} como novo admin`);
+                const tx = await ownerRules.propose_admin(
+                  {
+                    new_admin: newAdmin,
+                    expires_at: BigInt(
+                      Math.floor(Date.now() / 1000) + 7 * 86400,
+                    ),
+                  },
                   { publicKey: address } as any,
                 );
                 await (tx as any).signAndSend({ signTransaction });
-                log("success", "Admin transferido");
+                log(
+                  "success",
+                  "Proposta enviada; o novo admin deve aceitá-la em Admin",
+                );
               })
             }
             variant="primary"
             size="md"
             className="primary-action"
           >
-            Transferir Admin
+            Propor Novo Admin
           </Button>
         </div>
         <div className="card" style={{ marginTop: 12 }}>
@@ -366,7 +375,7 @@ const Settings: React.FC = () => {
         </div>
         <div className="card" style={{ marginTop: 12 }}>
           <Text as="h2" size="lg">
-            Supervisores (presence_events)
+            Supervisores (owner_rules)
           </Text>
           <Input
             label="Endereço"
@@ -379,38 +388,44 @@ const Settings: React.FC = () => {
             <Button
               onClick={() =>
                 void call(async () => {
-                  log("info", `Ativando supervisor ${(supAddr || address)!}`);
-                  const tx = await (presenceEvents as any).set_supervisor(
-                    { user: supAddr || address!, status: true },
+                  setSupClaimLink("");
+                  log(
+                    "info",
+                    `Gerando claim de supervisor para ${(supAddr || address)!}`,
+                  );
+                  const tx = await ownerRules.generate_supervisor_claim_link(
+                    { recipient: supAddr || address!, expires_at: undefined },
                     { publicKey: address },
                   );
-                  const need = (tx as any).needsNonInvokerSigningBy as
-                    | string[]
-                    | undefined;
-                  if (need && need.length && need[0] && need[0] !== address) {
-                    addNotification(
-                      `Assine com ${need[0]} para ativar supervisor`,
-                      "warning",
-                    );
-                    log("info", `Assine com ${need[0]} para ativar supervisor`);
-                    return;
-                  }
-                  await (tx as any).signAndSend({ signTransaction });
-                  log("success", "Supervisor ativado");
+                  const sent = await (tx as any).signAndSend({
+                    signTransaction,
+                  });
+                  const res: any = sent.result;
+                  const token = res?.unwrap ? res.unwrap() : res;
+                  const tokenHex = Buffer.isBuffer(token)
+                    ? Buffer.from(token).toString("hex")
+                    : String(token);
+                  setSupClaimLink(
+                    `${window.location.origin}/claim?token=${tokenHex}`,
+                  );
+                  log(
+                    "success",
+                    "Claim de supervisor gerado; o destinatário deve resgatá-lo",
+                  );
                 })
               }
               variant="primary"
               size="md"
               className="primary-action"
             >
-              Ativar Supervisor
+              Gerar Claim de Supervisor
             </Button>
             <Button
               onClick={() =>
                 void call(async () => {
                   log("info", `Revogando supervisor ${(supAddr || address)!}`);
-                  const tx = await (presenceEvents as any).set_supervisor(
-                    { user: supAddr || address!, status: false },
+                  const tx = await ownerRules.revoke_credential(
+                    { wallet: supAddr || address!, role_id: 2 },
                     { publicKey: address },
                   );
                   const need = (tx as any).needsNonInvokerSigningBy as
@@ -437,6 +452,11 @@ const Settings: React.FC = () => {
               Revogar Supervisor
             </Button>
           </div>
+          {supClaimLink && (
+            <Text as="p" size="sm">
+              Link de claim: {supClaimLink}
+            </Text>
+          )}
         </div>
         <div className="card" style={{ marginTop: 12 }}>
           <Text as="h2" size="lg" style={{ color: "#ff9f43" }}>
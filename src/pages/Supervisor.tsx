@@ -22,17 +22,19 @@ const Supervisor: React.FC = () => {
   // sequence not needed when using signAndSend via contract client
 
   const gen = async () => {
+    if (!address) return;
     setMsg("");
     try {
-      const { result } = await ownerRules.generate_associate_claim_link({ recipient, operator: address! });
-      if ((result as any)?.isOk?.()) {
-        const hash = (result as any).unwrap();
-        setClaim(String(hash));
-        addNotification("Token de claim gerado", "success");
-      } else {
-        setMsg("Falha ao gerar token");
-        addNotification("Falha ao gerar token", "error");
-      }
+      const tx = await (ownerRules as any).generate_associate_claim_link(
+        { recipient, operator: address, expires_at: undefined },
+        { publicKey: address }
+      );
+      const sent = await (tx as any).signAndSend({ signTransaction });
+      const token = Buffer.isBuffer(sent.result)
+        ? Buffer.from(sent.result as any).toString("hex")
+        : String(sent.result);
+      setClaim(token);
+      addNotification("Token de claim gerado", "success");
     } catch (e: any) {
       setMsg(e?.message || "Erro");
       addNotification("Erro ao gerar token", "error");
@@ -44,15 +46,6 @@ const Supervisor: React.FC = () => {
     setMsg("");
     try {
       addNotification("Preparando criação de evento", "primary");
-      try {
-        const txSup = await (presenceEvents as any).set_supervisor(
-          { user: address, status: true },
-          { publicKey: address }
-        );
-        await (txSup as any).signAndSend({ signTransaction });
-      } catch {
-        // ignore if already supervisor or unauthorized; create_event will validate
-      }
       const tx = await (presenceEvents as any).create_event(
         { name: evtName, start_ts: BigInt(startTs), end_ts: BigInt(endTs), operator: address },
         { publicKey: address }
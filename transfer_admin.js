@@ -12,15 +12,17 @@ async function transferAdmin() {
     console.log(`Novo admin: ${NEW_ADMIN_ADDRESS}`);
     
     // Enviar transação de transferência
-    const tx = await ownerRules.transfer_admin({
-      new_admin: NEW_ADMIN_ADDRESS
+    // A transferência só é concluída quando o novo admin chamar accept_admin
+    const tx = await ownerRules.propose_admin({
+      new_admin: NEW_ADMIN_ADDRESS,
+      expires_at: BigInt(Math.floor(Date.now() / 1000) + 7 * 86400)
     });
     
     console.log('Transação preparada:', tx);
     
     // Assinar e enviar a transação
     const result = await tx.signAndSend();
-    console.log('Transferência concluída com sucesso!');
+    console.log('Proposta enviada! O novo admin deve chamar accept_admin para concluir.');
     console.log('Resultado:', result);
     
   } catch (error) {
@@ -6,11 +6,16 @@ echo "Transferindo admin do contrato owner-rules..."
 echo "Contrato: CAOHH2TRQKPII3HHCFJUGK4LR5QECAT7OUFNKL3OKKOA6EXKTJ576463"
 echo "Novo admin: GAPILR4XRM3HEYVGOVIP7NNXS4CKOHJDNIOM45TACPJ4CTMBA2RIBP5C"
 
-# Comando para transferir admin
+# Passo 1: o admin atual propõe o novo admin (proposta válida por 7 dias)
 stellar contract invoke \
   --id CAOHH2TRQKPII3HHCFJUGK4LR5QECAT7OUFNKL3OKKOA6EXKTJ576463 \
   --source testnet-user \
   --network testnet \
   -- \
-  transfer_admin \
-  --new_admin GAPILR4XRM3HEYVGOVIP7NNXS4CKOHJDNIOM45TACPJ4CTMBA2RIBP5C
\ No newline at end of file
+  propose_admin \
+  --new_admin GAPILR4XRM3HEYVGOVIP7NNXS4CKOHJDNIOM45TACPJ4CTMBA2RIBP5C \
+  --expires_at $(( $(date +%s) + 7 * 86400 ))
+
+# Passo 2: o novo admin aceita com a própria conta
+echo "Para concluir, o novo admin deve executar:"
+echo "stellar contract invoke --id CAOHH2TRQKPII3HHCFJUGK4LR5QECAT7OUFNKL3OKKOA6EXKTJ576463 --source <CONTA_NOVO_ADMIN> --network testnet -- accept_admin"
\ No newline at end of file
@@ -11,8 +11,10 @@ console.log('');
 console.log('Para transferir o admin, você precisa:');
 console.log('1. Conectar sua wallet como admin atual no frontend');
 console.log('2. Ir para a página Admin (http://localhost:5173/admin)');
-console.log('3. Usar a função "transfer_admin" com o endereço:');
+console.log('3. Usar a função "propose_admin" com o endereço:');
 console.log(`   ${NEW_ADMIN_ADDRESS}`);
+console.log('4. Conectar a wallet do novo admin e chamar "accept_admin" antes da proposta expirar');
 console.log('');
-console.log('Ou execute este comando manualmente com a conta admin:');
-console.log(`stellar contract invoke --id ${OWNER_RULES_CONTRACT_ID} --source <CONTA_ADMIN> --network testnet -- transfer_admin --new_admin ${NEW_ADMIN_ADDRESS}`);
\ No newline at end of file
+console.log('Ou execute estes comandos manualmente (primeiro com a conta admin, depois com a do novo admin):');
+console.log(`stellar contract invoke --id ${OWNER_RULES_CONTRACT_ID} --source <CONTA_ADMIN> --network testnet -- propose_admin --new_admin ${NEW_ADMIN_ADDRESS} --expires_at <UNIX_TIMESTAMP>`);
+console.log(`stellar contract invoke --id ${OWNER_RULES_CONTRACT_ID} --source <CONTA_NOVO_ADMIN> --network testnet -- accept_admin`);
\ No newline at end of file